@@ -18,6 +18,9 @@ type (
 		InvestmentStakedBalance               decimal.Decimal `json:"investment_staked_balance"`
 		UnstakedBalance                       decimal.Decimal `json:"unstaked_balance"`
 		IsOnlyForInvestment                   bool            `json:"is_only_for_investment"`
+		IsRetiring                            bool            `json:"is_retiring"`
+		IsUnderperforming                     bool            `json:"is_underperforming"`
+		Score                                 *uint64         `json:"score"`
 		LastUpdateEpochHeight                 uint64          `json:"last_update_epoch_height"`
 		LastClassicStakeIncreasingEpochHeight *uint64         `json:"last_classic_stake_increasing_epoch_height"`
 	}
@@ -16,6 +16,30 @@ import (
 
 var minRebalanceStake = decimal.New(1, 24)
 
+const (
+	validatorRegistryPageLimit = 50
+	validatorsBatchUpdateLimit = 10
+)
+
+func (s *Service) getValidatorRegistry() ([]Validator, error) {
+	var validators []Validator
+	for fromIndex := 0; ; fromIndex += validatorRegistryPageLimit {
+		argsMarshaled, _ := json.Marshal(map[string]interface{}{
+			"from_index": fromIndex,
+			"limit":      validatorRegistryPageLimit,
+		})
+		var page []Validator
+		err := s.callContractWithUnmarshal("get_validator_registry", base64.StdEncoding.EncodeToString(argsMarshaled), &page)
+		if err != nil {
+			return nil, errors.Wrap(err, "callContractWithUnmarshal(get_validator_registry)")
+		}
+		validators = append(validators, page...)
+		if len(page) < validatorRegistryPageLimit {
+			return validators, nil
+		}
+	}
+}
+
 func (s *Service) PoolUpdate() error {
 	err := s.takeUnstakedBalance()
 	if err != nil {
@@ -47,39 +71,54 @@ func (s *Service) PoolUpdate() error {
 		// TODO notify
 	}
 
-	var validators []Validator
-	err = s.callContractWithUnmarshal("get_validator_registry", "", &validators)
+	validators, err := s.getValidatorRegistry()
 	if err != nil {
-		return errors.Wrap(err, "callContractWithUnmarshal(get_validator_registry)")
-	}
-	for _, v := range validators {
-		if v.LastUpdateEpochHeight == epochs.NetworkEpochHeight {
-			s.log.Warn("PoolUpdate: validator already updated", zap.String("validator", v.AccountID))
+		return errors.Wrap(err, "getValidatorRegistry")
+	}
+	for fromIndex := 0; fromIndex < len(validators); fromIndex += validatorsBatchUpdateLimit {
+		toIndex := fromIndex + validatorsBatchUpdateLimit
+		if toIndex > len(validators) {
+			toIndex = len(validators)
+		}
+		isUpdated := true
+		for _, v := range validators[fromIndex:toIndex] {
+			if v.LastUpdateEpochHeight == epochs.NetworkEpochHeight {
+				s.log.Warn("PoolUpdate: validator already updated", zap.String("validator", v.AccountID))
+				continue
+			}
+			isUpdated = false
+		}
+		if isUpdated {
 			continue
 		}
-		argsMarshaled, _ := json.Marshal(map[string]string{"validator_account_id": v.AccountID})
+		argsMarshaled, _ := json.Marshal(map[string]interface{}{
+			"from_index": fromIndex,
+			"limit":      validatorsBatchUpdateLimit,
+		})
 		res, err := s.cli.TransactionSendAwait(s.ctx, s.cfg.KeyPairAccountID, s.cfg.StakePool, []action.Action{
-			action.NewFunctionCall("update_validator", argsMarshaled, types.DefaultFunctionCallGas*10, types.BalanceFromFloat(0)),
+			action.NewFunctionCall("update_validators", argsMarshaled, types.DefaultFunctionCallGas*10, types.BalanceFromFloat(0)),
 		}, client.WithLatestBlock(),
 			client.WithKeyPair(s.keyPair),
 		)
 		if err != nil {
-			return errors.Wrapf(err, "TransactionSendAwait[validator:%s]", v.AccountID)
+			return errors.Wrapf(err, "TransactionSendAwait[from_index:%d]", fromIndex)
 		}
 		if res.Status.Failure != nil {
 			return errors.New(string(res.Status.Failure))
 		}
 		data, _ := base64.StdEncoding.DecodeString(res.Status.SuccessValue)
-		var resp CallbackResult
+		var resp []CallbackResult
 		err = json.Unmarshal(data, &resp)
 		if err != nil {
 			return errors.Wrap(err, "json.Unmarshal(resp)")
 		}
-		if !resp.IsSuccess {
-			return errors.Errorf("fail result from validator %s", v.AccountID)
-		}
-		if resp.NetworkEpochHeight != epochs.NetworkEpochHeight {
-			return errors.Errorf("mismatch epoch after update %d != %d", resp.NetworkEpochHeight, epochs.NetworkEpochHeight)
+		for _, r := range resp {
+			if !r.IsSuccess {
+				return errors.Errorf("fail result from validators page %d", fromIndex)
+			}
+			if r.NetworkEpochHeight != epochs.NetworkEpochHeight {
+				return errors.Errorf("mismatch epoch after update %d != %d", r.NetworkEpochHeight, epochs.NetworkEpochHeight)
+			}
 		}
 	}
 
@@ -153,15 +192,14 @@ func (s *Service) IncreaseStake() error {
 		return errors.Wrap(err, "epochs are different")
 	}
 
-	var validators []Validator
-	err = s.callContractWithUnmarshal("get_validator_registry", "", &validators)
+	validators, err := s.getValidatorRegistry()
 	if err != nil {
-		return errors.Wrap(err, "callContractWithUnmarshal(get_validator_registry)")
+		return errors.Wrap(err, "getValidatorRegistry")
 	}
 
 	var filteredValidators []Validator
 	for _, validator := range validators {
-		if validator.IsOnlyForInvestment {
+		if validator.IsOnlyForInvestment || validator.IsRetiring || validator.IsUnderperforming {
 			continue
 		}
 		if validator.LastClassicStakeIncreasingEpochHeight == nil || *validator.LastClassicStakeIncreasingEpochHeight < epochs.PoolEpochHeight {
@@ -288,14 +326,13 @@ func (s *Service) requestedDecreaseValidatorStake() error {
 		return errors.Wrap(err, "callContractWithUnmarshal(get_requested_to_withdrawal_fund)")
 	}
 
-	var validators []Validator
-	err = s.callContractWithUnmarshal("get_validator_registry", "", &validators)
+	validators, err := s.getValidatorRegistry()
 	if err != nil {
-		return errors.Wrap(err, "callContractWithUnmarshal(get_validator_registry)")
+		return errors.Wrap(err, "getValidatorRegistry")
 	}
 	var filteredValidators []Validator
 	for _, validator := range validators {
-		if !validator.IsOnlyForInvestment && validator.ClassicStakedBalance.GreaterThan(decimal.Zero) {
+		if !validator.IsOnlyForInvestment && !validator.IsRetiring && validator.ClassicStakedBalance.GreaterThan(decimal.Zero) {
 			filteredValidators = append(filteredValidators, validator)
 		}
 	}
@@ -426,10 +463,9 @@ func (s *Service) takeUnstakedBalance() error {
 		s.log.Debug("takeUnstakedBalance: not yet")
 		return nil
 	}
-	var validators []Validator
-	err = s.callContractWithUnmarshal("get_validator_registry", "", &validators)
+	validators, err := s.getValidatorRegistry()
 	if err != nil {
-		return errors.Wrap(err, "callContractWithUnmarshal(get_validator_registry)")
+		return errors.Wrap(err, "getValidatorRegistry")
 	}
 	for _, validator := range validators {
 		if validator.LastUpdateEpochHeight == epochs.NetworkEpochHeight {
@@ -0,0 +1,71 @@
+use near_sdk::{AccountId, Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedMap;
+use super::config::Config;
+use super::delayed_withdrawal::DelayedWithdrawal;
+use super::storage_key::StorageKey;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AccountDelayedWithdrawal {
+    /// Storage
+    /// u64 - delayed withdrawal id, it is also the id of the non-fungible token representing the delayed withdrawal.
+    pub delayed_withdrawal_registry: UnorderedMap<u64, DelayedWithdrawal>,
+    /// Total Near amount of all delayed withdrawals of the account.
+    pub near_amount: Balance,
+    /// Storage staking price paid for this object. If the object was created by receiving a delayed withdrawal
+    /// from another account, it is the price paid by the sender for its emptied object.
+    pub storage_staking_price: Balance
+}
+
+impl AccountDelayedWithdrawal {
+    pub fn new(account_id: AccountId, storage_staking_price: Balance) -> Self {
+        Self {
+            delayed_withdrawal_registry: Self::initialize_delayed_withdrawal_registry(account_id),
+            near_amount: 0,
+            storage_staking_price
+        }
+    }
+
+    pub fn initialize_delayed_withdrawal_registry(account_id: AccountId) -> UnorderedMap<u64, DelayedWithdrawal> {
+        UnorderedMap::new(StorageKey::DelayedWithdrawal { account_id })
+    }
+
+    /// Removes all delayed withdrawals available for taking and returns their ids and Near amount.
+    pub fn take_matured_delayed_withdrawals(&mut self, current_epoch_height: EpochHeight, config: &Config) -> (Vec<u64>, Balance) {
+        let matured_delayed_withdrawal_id_registry: Vec<u64> = self.delayed_withdrawal_registry
+            .iter()
+            .filter(|(_, delayed_withdrawal)| delayed_withdrawal.can_take_delayed_withdrawal(current_epoch_height, config))
+            .map(|(delayed_withdrawal_id, _)| delayed_withdrawal_id)
+            .collect();
+
+        let mut near_amount: Balance = 0;
+
+        for delayed_withdrawal_id in matured_delayed_withdrawal_id_registry.iter() {
+            if let Some(delayed_withdrawal) = self.delayed_withdrawal_registry.remove(delayed_withdrawal_id) {
+                near_amount += delayed_withdrawal.near_amount;
+            }
+        }
+
+        self.near_amount -= near_amount;
+
+        (matured_delayed_withdrawal_id_registry, near_amount)
+    }
+
+    /// Near amount of the delayed withdrawals available for taking.
+    pub fn get_matured_near_amount(&self, current_epoch_height: EpochHeight, config: &Config) -> Balance {
+        self.delayed_withdrawal_registry
+            .values()
+            .filter(|delayed_withdrawal| delayed_withdrawal.can_take_delayed_withdrawal(current_epoch_height, config))
+            .map(|delayed_withdrawal| delayed_withdrawal.near_amount)
+            .sum()
+    }
+
+    /// Minimum epoch quantity after passing which some delayed withdrawal becomes available for taking.
+    pub fn get_epoch_quantity_to_take_delayed_withdrawal(&self, current_epoch_height: EpochHeight, config: &Config) -> u64 {
+        self.delayed_withdrawal_registry
+            .values()
+            .map(|delayed_withdrawal| delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(current_epoch_height, config))
+            .min()
+            .unwrap_or(0)
+    }
+}
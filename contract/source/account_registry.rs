@@ -5,6 +5,10 @@ use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 pub struct AccountRegistry {
     pub owner_id: AccountId,
     pub manager_id: AccountId,
+    /// Account that is proposed to become the owner. It becomes the owner only after accepting.
+    pub pending_owner_id: Option<AccountId>,
+    /// Account that is proposed to become the manager. It becomes the manager only after accepting.
+    pub pending_manager_id: Option<AccountId>,
     /// Id of account, that will receive 'self_fee'.
     pub self_fee_receiver_account_id: AccountId,
     /// Id of account, that will receive 'partner_fee'.
@@ -0,0 +1,59 @@
+use near_sdk::{Balance, EpochHeight, env};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::DEFAULT_EPOCH_QUANTITY_FOR_DELAYED_WITHDRAWAL;
+use super::DEFAULT_EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE;
+use super::DEFAULT_MINIMUM_DEPOSIT_AMOUNT;
+use super::DEFAULT_MINIMUM_NUMBER_OF_TGAS;
+use super::MAXIMUM_NUMBER_OF_TGAS;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Config {
+    /// Quantity of epochs after passing which it is possible to withdraw fund.
+    pub epoch_quantity_for_delayed_withdrawal: u64,
+    /// Quantity of epochs after passing which it is possible to request unstake from validator.
+    pub epoch_quantity_for_validator_unstake: u64,
+    /// Minimum Gas amount for common transaction.
+    pub minimum_number_of_tgas: u64,
+    /// The minimum Near amount that must be attached to a transaction.
+    pub minimum_deposit_amount: Balance
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self {
+            epoch_quantity_for_delayed_withdrawal: DEFAULT_EPOCH_QUANTITY_FOR_DELAYED_WITHDRAWAL,
+            epoch_quantity_for_validator_unstake: DEFAULT_EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE,
+            minimum_number_of_tgas: DEFAULT_MINIMUM_NUMBER_OF_TGAS,
+            minimum_deposit_amount: DEFAULT_MINIMUM_DEPOSIT_AMOUNT
+        }
+    }
+
+    /// The funds requested in some epoch are unstaked no later than in the next right epoch and are
+    /// taken from the validator in the right epoch after it, so the delayed withdrawal must last at least two unstake periods.
+    pub fn assert_valid_epoch_quantities(epoch_quantity_for_delayed_withdrawal: u64, epoch_quantity_for_validator_unstake: u64) {
+        if epoch_quantity_for_validator_unstake == 0 {
+            env::panic_str("Epoch quantity for validator unstake must be greater than zero.");
+        }
+
+        match epoch_quantity_for_validator_unstake.checked_mul(2) {
+            Some(minimum_epoch_quantity_for_delayed_withdrawal) => {
+                if epoch_quantity_for_delayed_withdrawal < minimum_epoch_quantity_for_delayed_withdrawal {
+                    env::panic_str("Epoch quantity for delayed withdrawal must be at least twice the epoch quantity for validator unstake.");
+                }
+            }
+            None => {
+                env::panic_str("Epoch quantity for validator unstake is too large.");
+            }
+        }
+    }
+
+    pub fn assert_valid_minimum_number_of_tgas(minimum_number_of_tgas: u64) {
+        if minimum_number_of_tgas == 0 || minimum_number_of_tgas > MAXIMUM_NUMBER_OF_TGAS {
+            env::panic_str("Minimum number of TGas must be greater than zero and less than or equal to maximum number of TGas.");
+        }
+    }
+
+    pub fn is_right_epoch(&self, epoch_height: EpochHeight) -> bool {
+        epoch_height.is_multiple_of(self.epoch_quantity_for_validator_unstake)
+    }
+}
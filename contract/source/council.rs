@@ -0,0 +1,84 @@
+use near_sdk::{env, AccountId, EpochHeight, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedMap;
+use super::get_account_id_with_maximum_length;
+use super::proposal::Proposal;
+use super::storage_key::StorageKey;
+use super::MAXIMUM_QUANTITY_OF_COUNCIL_MEMBERS;
+
+/// M-of-N approval of the sensitive management actions. The council is active if the threshold is not zero.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Council {
+    pub member_account_ids: Vec<AccountId>,
+    /// Quantity of member confirmations required to execute the proposal.
+    pub threshold: u64,
+    /// Quantity of epochs that must pass after reaching the threshold before the proposal execution.
+    pub execution_delay_epoch_quantity: u64,
+    /// Storage.
+    /// u64 - proposal id.
+    pub proposal_registry: UnorderedMap<u64, Proposal>,
+    pub next_proposal_id: u64,
+    /// In bytes.
+    pub storage_usage_per_confirmation: StorageUsage
+}
+
+impl Council {
+    pub fn new() -> Self {
+        Self {
+            member_account_ids: Vec::new(),
+            threshold: 0,
+            execution_delay_epoch_quantity: 0,
+            proposal_registry: UnorderedMap::new(StorageKey::Proposal),
+            next_proposal_id: 0,
+            storage_usage_per_confirmation: Self::calculate_storage_usage_per_additional_confirmation()
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.threshold > 0
+    }
+
+    pub fn is_member(&self, account_id: &AccountId) -> bool {
+        self.member_account_ids.contains(account_id)
+    }
+
+    /// Only confirmations of the current members are counted, so the council change does not leave extra confirmations.
+    pub fn is_threshold_reached(&self, proposal: &Proposal) -> bool {
+        let confirmations_quantity = proposal.confirmation_registry
+            .iter()
+            .filter(|account_id| self.is_member(account_id))
+            .count() as u64;
+
+        self.is_active() && confirmations_quantity >= self.threshold
+    }
+
+    pub fn calculate_executable_epoch_height(&self, current_epoch_height: EpochHeight) -> EpochHeight {
+        current_epoch_height + self.execution_delay_epoch_quantity
+    }
+
+    pub fn assert_valid_members(member_account_ids: &[AccountId], threshold: u64) {
+        if member_account_ids.len() as u64 > MAXIMUM_QUANTITY_OF_COUNCIL_MEMBERS {
+            env::panic_str("Too many council members.");
+        }
+        if threshold > member_account_ids.len() as u64 {
+            env::panic_str("Threshold must be less than or equal to the quantity of council members.");
+        }
+        if threshold == 0 && !member_account_ids.is_empty() {
+            env::panic_str("Threshold must be greater than zero.");
+        }
+        for (index, account_id) in member_account_ids.iter().enumerate() {
+            if member_account_ids[..index].contains(account_id) {
+                env::panic_str("Council members must be unique.");
+            }
+        }
+    }
+
+    fn calculate_storage_usage_per_additional_confirmation() -> StorageUsage {
+        match get_account_id_with_maximum_length().try_to_vec() {
+            Ok(confirmation) => confirmation.len() as StorageUsage,
+            Err(_) => {
+                env::panic_str("Nonexecutable code. Object must be serializable.");
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use near_sdk::{ext_contract, AccountId};
 use near_sdk::json_types::U128;
+use crate::fee::Fee;
 
 /// Default Near `staking pool` contract interface.
 #[ext_contract(classic_validator)]
@@ -25,4 +26,6 @@ pub trait ClassicValidator {
     fn get_account_unstaked_balance(&self, account_id: AccountId) -> U128;
 
     fn get_account_total_balance(&self, account_id: AccountId) -> U128;
+
+    fn get_reward_fee_fraction(&self) -> Fee;
 }
\ No newline at end of file
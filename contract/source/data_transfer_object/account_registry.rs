@@ -0,0 +1,15 @@
+use near_sdk::AccountId;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountRegistry {
+    pub owner_id: AccountId,
+    pub manager_id: AccountId,
+    /// Account that is proposed to become the owner and has not accepted yet.
+    pub pending_owner_id: Option<AccountId>,
+    /// Account that is proposed to become the manager and has not accepted yet.
+    pub pending_manager_id: Option<AccountId>,
+    pub self_fee_receiver_account_id: AccountId,
+    pub partner_fee_receiver_account_id: AccountId
+}
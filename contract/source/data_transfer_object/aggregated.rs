@@ -16,5 +16,7 @@ pub struct Aggregated {
     /// Near amount of rewards from validators.
     pub total_rewards_from_validators_near_amount: U128,
     /// Fee charged by the pool when receiving rewards from validators.
-    pub reward_fee: Option<Fee>
+    pub reward_fee: Option<Fee>,
+    /// The pool is not updated in the current network epoch yet, so the exchange rate does not include the last rewards.
+    pub is_exchange_rate_stale: bool
 }
\ No newline at end of file
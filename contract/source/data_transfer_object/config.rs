@@ -0,0 +1,11 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Config {
+    pub epoch_quantity_for_delayed_withdrawal: u64,
+    pub epoch_quantity_for_validator_unstake: u64,
+    pub minimum_number_of_tgas: u64,
+    pub minimum_deposit_amount: U128
+}
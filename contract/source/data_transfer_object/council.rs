@@ -0,0 +1,12 @@
+use near_sdk::AccountId;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Council {
+    pub member_account_ids: Vec<AccountId>,
+    /// The council is not active if the threshold is zero.
+    pub threshold: u64,
+    pub execution_delay_epoch_quantity: u64,
+    pub proposals_quantity: u64
+}
@@ -0,0 +1,14 @@
+use near_sdk::EpochHeight;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DelayedWithdrawal {
+    /// Id of the non-fungible token representing the delayed withdrawal.
+    pub token_id: String,
+    pub near_amount: U128,
+    pub started_epoch_height: EpochHeight,
+    pub unlock_epoch_height: EpochHeight,
+    pub epoch_quantity_to_take_delayed_withdrawal: u64
+}
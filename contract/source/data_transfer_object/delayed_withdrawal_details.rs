@@ -5,5 +5,7 @@ use near_sdk::serde::{Deserialize, Serialize};
 #[serde(crate = "near_sdk::serde")]
 pub struct DelayedWithdrawalDetails {
     pub epoch_quantity_to_take_delayed_withdrawal: u64,
-    pub near_amount: U128
+    pub near_amount: U128,
+    /// Near amount of the delayed withdrawals available for taking.
+    pub matured_near_amount: U128
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use near_sdk::Balance;
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Amounts of the deposit passed to the callback of the deposit on the validator.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DepositDetails {
+    pub attached_deposit: Balance,
+    /// Near amount deposited on the validator.
+    pub near_amount: Balance,
+    pub refundable_near_amount: Balance,
+    pub fee_near_amount: Balance,
+    pub token_amount: Balance,
+    /// Near amount that was not exchanged for the tokens.
+    pub near_remainder: Balance,
+    pub storage_staking_price_per_additional_accounts: Balance
+}
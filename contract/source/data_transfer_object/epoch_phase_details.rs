@@ -0,0 +1,21 @@
+use near_sdk::{AccountId, EpochHeight};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::epoch_phase::EpochPhase;
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EpochPhaseDetails {
+    pub epoch_phase: EpochPhase,
+    pub pool_epoch_height: EpochHeight,
+    pub network_epoch_height: EpochHeight,
+    /// Validators from the requested page that should be updated.
+    pub validators_to_update: Vec<AccountId>,
+    /// Validators from the requested page from which the unstaked balance can be taken.
+    pub validators_to_take_unstaked_balance: Vec<AccountId>,
+    /// Validators from the requested page from which the investment withdrawal should be requested.
+    pub validators_to_request_investment_withdrawal: Vec<AccountId>,
+    pub needed_to_request_classic_near_amount: U128,
+    pub needed_to_request_investment_near_amount: U128,
+    pub classic_unstaked_balance: U128
+}
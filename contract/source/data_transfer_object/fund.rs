@@ -1,5 +1,6 @@
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
+use super::redelegation::Redelegation;
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
@@ -8,10 +9,16 @@ pub struct Fund {
     pub classic_unstaked_balance: U128,
     /// Near amount already distributed on validators by pool.
     pub classic_staked_balance: U128,
+    /// Near part of the liquidity pool for the instant withdrawal, it is not included into the common balance.
+    pub classic_liquidity_balance: U128,
     /// Near amount already distributed on validators by investors.
     pub investment_staked_balance: U128,
     /// Near amount already distributed on validators by pool and investors.
     pub common_staked_balance: U128,
     /// Common management near amount.
-    pub common_balance: U128
+    pub common_balance: U128,
+    /// The pool is not updated in the current network epoch yet, so the exchange rate does not include the last rewards.
+    pub is_exchange_rate_stale: bool,
+    /// Classic stake moves between validators that are not completed yet.
+    pub redelegation_registry: Vec<Redelegation>
 }
\ No newline at end of file
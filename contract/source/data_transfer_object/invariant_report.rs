@@ -0,0 +1,54 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Invariant {
+    /// Validators quantity is equal to the quantity of the registered validators.
+    ValidatorsQuantity,
+    /// Classic and requested to restaking Near amounts of validators are equal to the classic staked balance together with
+    /// the classic Near amount needed to request from validators and the rewards not added to the classic staked balance yet.
+    ClassicStakedBalance,
+    /// Investment Near amount of validators together with the moving investment Near amount is equal to the investment
+    /// staked balance together with the investment Near amount needed to request from validators.
+    InvestmentStakedBalance,
+    /// Fee receiver is registered in the fungible token.
+    FeeReceiverRegistration,
+    /// Near balance of the pool covers the unstaked, liquidity, delayed withdrawn, keeper and storage staking funds.
+    NearBalance,
+    /// Listed investor has the investment.
+    InvestorRegistration,
+    /// Distributions quantity of the investor is equal to the quantity of the found distributions.
+    InvestorDistributionsQuantity,
+    /// Distributions of the investor together with the moving Near amount are equal to the investor staked balance.
+    InvestorStakedBalance,
+    /// Staked balances of all listed investors are equal to the investment staked balance.
+    InvestorsStakedBalance
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Discrepancy {
+    pub invariant: Invariant,
+    pub account_id: Option<AccountId>,
+    pub expected: U128,
+    pub actual: U128
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct InvariantReport {
+    /// Discrepancies of the pool invariants and of the invariants of the validators and investors on the page.
+    pub discrepancy_registry: Vec<Discrepancy>,
+    /// Classic and requested to restaking Near amounts of the validators on the page.
+    pub validators_classic_near_amount: U128,
+    /// Investment Near amount of the validators on the page.
+    pub validators_investment_near_amount: U128,
+    /// Staked balance of the listed investors on the page.
+    pub investors_staked_balance: U128,
+    /// Index of the next page of the validators and listed investors.
+    pub next_index: Option<u64>,
+    /// Requested decrease of the stake of the validator on the page is in progress, so the balance invariants can be temporarily broken.
+    pub is_stake_decreasing: bool
+}
@@ -0,0 +1,12 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct InvestmentMove {
+    pub source_validator_account_id: AccountId,
+    pub target_validator_account_id: AccountId,
+    pub near_amount: U128,
+    pub epoch_quantity_to_complete: u64
+}
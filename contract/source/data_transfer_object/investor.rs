@@ -0,0 +1,13 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::fee::Fee;
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Investor {
+    pub investor_account_id: AccountId,
+    pub maximum_staked_balance: Option<U128>,
+    pub allowed_validator_account_ids: Option<Vec<AccountId>>,
+    pub deposit_fee: Option<Fee>
+}
@@ -1,10 +1,15 @@
 use near_sdk::AccountId;
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
+use super::investment_move::InvestmentMove;
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct InvestorInvestment {
     pub distribution_registry: Vec<(AccountId, U128)>,
-    pub staked_balance: U128
+    pub staked_balance: U128,
+    /// Retiring validators from which the investor stake should be migrated.
+    pub validators_for_migration: Vec<AccountId>,
+    /// Investor stake move between validators that is not completed yet.
+    pub investment_move: Option<InvestmentMove>
 }
\ No newline at end of file
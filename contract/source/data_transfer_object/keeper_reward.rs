@@ -0,0 +1,13 @@
+use near_sdk::EpochHeight;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct KeeperReward {
+    pub budget: U128,
+    pub near_amount_per_call: U128,
+    pub maximum_near_amount_per_epoch: U128,
+    pub paid_near_amount_in_current_epoch: U128,
+    pub current_epoch_height: EpochHeight
+}
@@ -0,0 +1,17 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LiquidityPool {
+    /// Near amount available for the instant withdrawal.
+    pub near_amount: U128,
+    /// Token amount received from the instant withdrawals.
+    pub token_amount: U128,
+    /// Near amount requested for the delayed withdrawal on rebalancing.
+    pub delayed_withdrawal_near_amount: U128,
+    /// Near value of the whole pool.
+    pub common_near_amount: U128,
+    pub total_share_amount: U128,
+    pub liquidity_providers_quantity: u64
+}
@@ -0,0 +1,10 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LiquidityProviderShare {
+    pub share_amount: U128,
+    /// Near value of the share.
+    pub near_amount: U128
+}
@@ -1,17 +1,34 @@
 pub(crate) mod account_balance;
+pub(crate) mod account_registry;
 pub(crate) mod aggregated;
 pub(crate) mod base_account_balance;
 pub(crate) mod callback_result;
+pub(crate) mod config;
+pub(crate) mod council;
+pub(crate) mod delayed_withdrawal;
 pub(crate) mod delayed_withdrawal_details;
+pub(crate) mod deposit_details;
 pub(crate) mod epoch_height_registry;
+pub(crate) mod epoch_phase_details;
 pub(crate) mod fee_registry_light;
 pub(crate) mod full_for_account;
 pub(crate) mod full;
 pub(crate) mod fund;
 pub(crate) mod fungible_token_metadata;
 pub(crate) mod investment_account_balance;
+pub(crate) mod investment_move;
+pub(crate) mod invariant_report;
 pub(crate) mod investor_investment;
+pub(crate) mod investor;
+pub(crate) mod keeper_reward;
+pub(crate) mod liquidity_pool;
+pub(crate) mod liquidity_provider_share;
+pub(crate) mod pause;
+pub(crate) mod proposal;
+pub(crate) mod redelegation;
 pub(crate) mod requested_to_withdrawal_fund;
 pub(crate) mod storage_staking_price;
 pub(crate) mod storage_staking_requested_coverage;
+pub(crate) mod validator_performance;
+pub(crate) mod validator_proposal;
 pub(crate) mod validator;
\ No newline at end of file
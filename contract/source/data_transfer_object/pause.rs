@@ -0,0 +1,14 @@
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Pause {
+    pub is_deposit_paused: bool,
+    pub is_deposit_on_validator_paused: bool,
+    pub is_instant_withdraw_paused: bool,
+    pub is_delayed_withdraw_paused: bool,
+    pub is_take_delayed_withdrawal_paused: bool,
+    pub is_ft_transfer_paused: bool,
+    pub is_validator_operations_paused: bool,
+    pub is_liquidity_pool_paused: bool
+}
@@ -0,0 +1,16 @@
+use near_sdk::{AccountId, EpochHeight};
+use near_sdk::json_types::U128;
+use near_sdk::serde::Serialize;
+use crate::proposal_action::ProposalAction;
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub proposal_id: u64,
+    pub proposer_account_id: AccountId,
+    pub action: ProposalAction,
+    pub near_amount: U128,
+    pub storage_staking_price: U128,
+    pub confirmation_account_ids: Vec<AccountId>,
+    pub executable_epoch_height: Option<EpochHeight>
+}
@@ -0,0 +1,14 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Redelegation {
+    pub source_validator_account_id: AccountId,
+    pub target_validator_account_id: AccountId,
+    /// Classic Near amount requested to move.
+    pub near_amount: U128,
+    /// Classic Near amount already unstaked from the source validator.
+    pub unstaked_near_amount: U128
+}
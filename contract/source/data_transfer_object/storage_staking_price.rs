@@ -4,10 +4,16 @@ use near_sdk::serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct StorageStakingPrice {
+    pub per_delayed_withdrawal_fund_account_delayed_withdrawal: U128,
     pub per_delayed_withdrawal_fund_delayed_withdrawal: U128,
     pub per_delayed_withdrawal_fund_investment_withdrawal: U128,
     pub per_fungible_token_account: U128,
+    pub per_liquidity_pool_share: U128,
+    pub per_council_proposal_confirmation: U128,
     pub per_validating_node_validator: U128,
     pub per_validating_node_investor: U128,
-    pub per_validating_node_distribution: U128
+    pub per_investor_registry_investor: U128,
+    pub per_validating_node_distribution: U128,
+    pub per_validating_node_redelegation: U128,
+    pub per_validating_node_investment_move: U128
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use near_sdk::{EpochHeight, AccountId};
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
+use crate::fee::Fee;
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
@@ -10,6 +11,14 @@ pub struct Validator {
     pub classic_staked_balance: U128,
     pub investment_staked_balance: U128,
     pub is_only_for_investment: bool,
+    pub is_retiring: bool,
     pub last_update_epoch_height: EpochHeight,
-    pub last_classic_stake_increasing_epoch_height: Option<EpochHeight>
+    pub last_classic_stake_increasing_epoch_height: Option<EpochHeight>,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub commission: Option<Fee>,
+    /// In basis points.
+    pub score: Option<u64>,
+    /// Validator score is less than the minimum validator score, so the pool does not stake on it.
+    pub is_underperforming: bool
 }
\ No newline at end of file
@@ -0,0 +1,26 @@
+use near_sdk::EpochHeight;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EpochReward {
+    pub epoch_height: EpochHeight,
+    pub epoch_quantity: u64,
+    pub staked_near_amount: U128,
+    pub rewards_near_amount: U128
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ValidatorPerformance {
+    /// From the oldest to the newest epoch reward.
+    pub epoch_reward_history: Vec<EpochReward>,
+    /// In basis points.
+    pub trailing_apy: Option<u64>,
+    pub missed_epochs_quantity: u64,
+    /// In basis points.
+    pub score: Option<u64>,
+    /// In basis points.
+    pub minimum_validator_score: Option<u64>
+}
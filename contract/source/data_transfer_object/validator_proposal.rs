@@ -0,0 +1,14 @@
+use near_sdk::AccountId;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::staking_contract_version::StakingContractVersion;
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ValidatorProposal {
+    pub validator_account_id: AccountId,
+    pub investor_account_id: AccountId,
+    pub staking_contract_version: StakingContractVersion,
+    pub near_amount: U128,
+    pub storage_staking_price: U128
+}
@@ -1,61 +1,108 @@
 use near_sdk::{Balance, AccountId, env, StorageUsage};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::LookupMap;
+use near_sdk::collections::{LookupMap, UnorderedMap};
+use super::account_delayed_withdrawal::AccountDelayedWithdrawal;
 use super::delayed_withdrawal::DelayedWithdrawal;
 use super::get_account_id_with_maximum_length;
 use super::investment_withdrawal::InvestmentWithdrawal;
+use super::legacy_delayed_withdrawal_registry::LegacyDelayedWithdrawalRegistry;
 use super::storage_key::StorageKey;
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct DelayedWithdrawnFund {
     /// Storage.
     /// AccountId - user account id.
-    pub delayed_withdrawal_registry: LookupMap<AccountId, DelayedWithdrawal>,
+    pub delayed_withdrawal_registry: LookupMap<AccountId, AccountDelayedWithdrawal>,
+    /// Storage.
+    /// u64 - delayed withdrawal id.
+    /// AccountId - owner of the delayed withdrawal.
+    pub delayed_withdrawal_owner_registry: UnorderedMap<u64, AccountId>,
+    /// Id of the next created delayed withdrawal.
+    pub next_delayed_withdrawal_id: u64,
     /// Storage
     /// AccountId - validator account id.
     /// Balance - Near amount.
     pub investment_withdrawal_registry: LookupMap<AccountId, InvestmentWithdrawal>,
     /// Classic Near amount needed to request from the validator.
     pub needed_to_request_classic_near_amount: Balance,
+    /// Classic Near amount requested for withdrawal while the pool epoch is behind the network epoch.
+    /// It is added to the needed to request classic Near amount on the pool update.
+    pub queued_to_request_classic_near_amount: Balance,
     /// Investment Near amount needed to request from the validator.
     pub needed_to_request_investment_near_amount: Balance,
     /// Near balance available for withdrawal after passing the delayed withdrawal process.
     pub balance: Balance,
     /// In bytes.
+    pub storage_usage_per_account_delayed_withdrawal: StorageUsage,
+    /// In bytes.
     pub storage_usage_per_delayed_withdrawal: StorageUsage,
     /// In bytes.
-    pub storage_usage_per_investment_withdrawal: StorageUsage
+    pub storage_usage_per_investment_withdrawal: StorageUsage,
+    /// Delayed withdrawals of the state migrated from the layout with one delayed withdrawal per account.
+    pub legacy_delayed_withdrawal_registry: Option<LegacyDelayedWithdrawalRegistry>
 }
 
 impl DelayedWithdrawnFund {
     pub fn new() -> Self {
         Self {
             delayed_withdrawal_registry: Self::initialize_delayed_withdrawal_registry(),
+            delayed_withdrawal_owner_registry: Self::initialize_delayed_withdrawal_owner_registry(),
+            next_delayed_withdrawal_id: 0,
             investment_withdrawal_registry: Self::initialize_investment_withdrawal_registry(),
             needed_to_request_classic_near_amount: 0,
+            queued_to_request_classic_near_amount: 0,
             needed_to_request_investment_near_amount: 0,
             balance: 0,
+            storage_usage_per_account_delayed_withdrawal: Self::calculate_storage_usage_per_additional_account_delayed_withdrawal(),
             storage_usage_per_delayed_withdrawal: Self::calculate_storage_usage_per_additional_delayed_withdrawal(),
-            storage_usage_per_investment_withdrawal: Self::calculate_storage_usage_per_additional_investment_withdrawal()
+            storage_usage_per_investment_withdrawal: Self::calculate_storage_usage_per_additional_investment_withdrawal(),
+            legacy_delayed_withdrawal_registry: None
         }
     }
 
-    fn calculate_storage_usage_per_additional_delayed_withdrawal() -> StorageUsage {
-        let mut delayed_withdrawal_registry = Self::initialize_delayed_withdrawal_registry();
+    /// The legacy delayed withdrawals are stored with the key of the delayed withdrawal registry, so the measuring
+    /// is done in the separate registry.
+    pub fn calculate_storage_usage_per_additional_account_delayed_withdrawal() -> StorageUsage {
+        let mut delayed_withdrawal_registry: LookupMap<AccountId, AccountDelayedWithdrawal> = LookupMap::new(StorageKey::StorageUsageMeasurement);
 
         let initial_storage_usage = env::storage_usage();
 
         let account_id = get_account_id_with_maximum_length();
 
+        delayed_withdrawal_registry.insert(&account_id, &AccountDelayedWithdrawal::new(account_id.clone(), 0));
+
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        delayed_withdrawal_registry.remove(&account_id);
+
+        storage_usage
+    }
+
+    pub fn calculate_storage_usage_per_additional_delayed_withdrawal() -> StorageUsage {
+        let account_id = get_account_id_with_maximum_length();
+
+        let mut delayed_withdrawal_registry = AccountDelayedWithdrawal::initialize_delayed_withdrawal_registry(account_id.clone());
+
+        let mut delayed_withdrawal_owner_registry = Self::initialize_delayed_withdrawal_owner_registry();
+
+        let initial_storage_usage = env::storage_usage();
+
         delayed_withdrawal_registry.insert(
-            &account_id,
+            &u64::MAX,
             &DelayedWithdrawal {
                 near_amount: 0,
                 started_epoch_height: env::epoch_height()
             }
         );
+        delayed_withdrawal_owner_registry.insert(&u64::MAX, &account_id);
 
-        env::storage_usage() - initial_storage_usage
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        // The registries are iterable, so the measured objects should not stay in the storage.
+        delayed_withdrawal_registry.remove(&u64::MAX);
+        delayed_withdrawal_owner_registry.remove(&u64::MAX);
+
+        storage_usage
     }
 
     fn calculate_storage_usage_per_additional_investment_withdrawal() -> StorageUsage {
@@ -76,10 +123,14 @@ impl DelayedWithdrawnFund {
         env::storage_usage() - initial_storage_usage
     }
 
-    fn initialize_delayed_withdrawal_registry() -> LookupMap<AccountId, DelayedWithdrawal> {
+    fn initialize_delayed_withdrawal_registry() -> LookupMap<AccountId, AccountDelayedWithdrawal> {
         LookupMap::new(StorageKey::DelayedWithdrawnFund)
     }
 
+    pub fn initialize_delayed_withdrawal_owner_registry() -> UnorderedMap<u64, AccountId> {
+        UnorderedMap::new(StorageKey::DelayedWithdrawalOwner)
+    }
+
     fn initialize_investment_withdrawal_registry() -> LookupMap<AccountId, InvestmentWithdrawal> {
         LookupMap::new(StorageKey::InvestmentWithdrawal)
     }
@@ -0,0 +1,22 @@
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Phase of the pool epoch cycle. The phases follow each other in the order of declaration
+/// after the network epoch changes.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum EpochPhase {
+    /// Pool epoch is equal to network epoch and the stake is distributed on validators.
+    Synced,
+    /// Network epoch has changed. Unstaked balance should be taken from validators and
+    /// all validators should be updated.
+    UpdatingValidators,
+    /// All validators are updated in the right epoch. Funds requested for the delayed withdrawal
+    /// should be unstaked from validators. The Near amount that can not be covered by the stake on validators
+    /// is carried into the next right epoch.
+    UnstakingRequested,
+    /// All maintenance steps are done. The pool state should be updated.
+    Finalizing,
+    /// Pool epoch is equal to network epoch. Classic unstaked balance should be distributed on validators
+    /// and the distribution should be confirmed.
+    Distributing
+}
@@ -0,0 +1,72 @@
+use near_sdk::{env, AccountId};
+use near_sdk::serde::Serialize;
+use near_sdk::serde_json;
+use super::pausable_operation::PausableOperation;
+use super::role::Role;
+
+const EVENT_STANDARD: &str = "stake_pool";
+const EVENT_STANDARD_VERSION: &str = "1.0.0";
+
+/// Pool events in the NEP-297 format.
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum Event<'a> {
+    Pause(&'a [PauseData<'a>]),
+    Unpause(&'a [PauseData<'a>]),
+    RoleGrant(&'a [RoleData<'a>]),
+    RoleRevoke(&'a [RoleData<'a>]),
+    ProposalCreate(&'a [ProposalData<'a>]),
+    ProposalConfirm(&'a [ProposalData<'a>]),
+    ProposalExecute(&'a [ProposalData<'a>]),
+    ProposalCancel(&'a [ProposalData<'a>])
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PauseData<'a> {
+    pub operation: PausableOperation,
+    pub account_id: &'a AccountId
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleData<'a> {
+    pub role: Role,
+    pub account_id: &'a AccountId,
+    pub owner_id: &'a AccountId
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalData<'a> {
+    pub proposal_id: u64,
+    pub account_id: &'a AccountId
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct EventLog<'a> {
+    standard: &'static str,
+    version: &'static str,
+    #[serde(flatten)]
+    event: &'a Event<'a>
+}
+
+impl<'a> Event<'a> {
+    pub fn emit(&self) {
+        let event_log = EventLog {
+            standard: EVENT_STANDARD,
+            version: EVENT_STANDARD_VERSION,
+            event: self
+        };
+
+        match serde_json::to_string(&event_log) {
+            Ok(event_log_) => env::log_str(format!("EVENT_JSON:{}", event_log_).as_str()),
+            Err(_) => {
+                env::panic_str("Nonexecutable code. Event must be serializable.");
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use near_sdk::Balance;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::delayed_withdrawal::DelayedWithdrawal;
 use super::delayed_withdrawn_fund::DelayedWithdrawnFund;
 
 #[derive(BorshDeserialize, BorshSerialize)]
@@ -9,11 +10,15 @@ pub struct Fund {
     /// Near amount already staked on validators in classic context.
     pub classic_staked_balance: Balance,
     /// Additional Near amount to ensure the possibility of instant withdrawal.
+    /// It is the Near part of the liquidity pool, it is not included into the common balance.
     pub classic_liquidity_balance: Balance,
     /// Near amount already staked on validators in investment context.
     pub investment_staked_balance: Balance,
     /// Fund that should be returned to users.
     pub delayed_withdrawn_fund: DelayedWithdrawnFund,
+    /// Near amount requested by the liquidity pool for the delayed withdrawal on rebalancing, it is a part of the
+    /// delayed withdrawn fund. It does not belong to any account, so it is not represented by the non-fungible token.
+    pub liquidity_pool_delayed_withdrawal: Option<DelayedWithdrawal>,
     pub is_distributed_on_validators_in_current_epoch: bool
 }
 
@@ -25,6 +30,7 @@ impl Fund {
             classic_liquidity_balance: 0,
             investment_staked_balance: 0,
             delayed_withdrawn_fund: DelayedWithdrawnFund::new(),
+            liquidity_pool_delayed_withdrawal: None,
             is_distributed_on_validators_in_current_epoch: false
         }
     }
@@ -0,0 +1,31 @@
+use near_sdk::{AccountId, Balance, EpochHeight, env};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::config::Config;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InvestmentMove {
+    /// Validator from which the investor stake is unstaked.
+    pub source_validator_account_id: AccountId,
+    /// Validator on which the investor stake should be restaked.
+    pub target_validator_account_id: AccountId,
+    /// Near amount that the investor requested to move.
+    pub near_amount: Balance,
+    /// It is only needed in order to understand when the unstaked funds become withdrawable,
+    /// the same as for DelayedWithdrawal.
+    pub started_epoch_height: EpochHeight
+}
+
+impl InvestmentMove {
+    pub fn get_epoch_quantity_to_complete_investment_move(&self, current_epoch_height: EpochHeight, config: &Config) -> u64 {
+        if current_epoch_height < self.started_epoch_height {
+            env::panic_str("Current epoch height must be greater or equal to started epoch height.");
+        }
+        let passed_epoch_height = current_epoch_height - self.started_epoch_height;
+
+        config.epoch_quantity_for_delayed_withdrawal.saturating_sub(passed_epoch_height)
+    }
+
+    pub fn can_complete_investment_move(&self, current_epoch_height: EpochHeight, config: &Config) -> bool {
+        self.get_epoch_quantity_to_complete_investment_move(current_epoch_height, config) == 0
+    }
+}
@@ -0,0 +1,69 @@
+use near_sdk::{env, AccountId, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedMap;
+use super::fee::Fee;
+use super::get_account_id_with_maximum_length;
+use super::investor_setting::InvestorSetting;
+use super::storage_key::StorageKey;
+use super::validator_proposal::ValidatorProposal;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InvestorRegistry {
+    /// Storage.
+    /// AccountId - investor account id.
+    pub investor_setting_registry: UnorderedMap<AccountId, InvestorSetting>,
+    /// Storage.
+    /// AccountId - proposed validator account id.
+    pub validator_proposal_registry: UnorderedMap<AccountId, ValidatorProposal>,
+    /// In bytes.
+    pub storage_usage_per_investor: StorageUsage
+}
+
+impl InvestorRegistry {
+    pub fn new() -> Self {
+        Self {
+            investor_setting_registry: Self::initialize_investor_setting_registry(),
+            validator_proposal_registry: Self::initialize_validator_proposal_registry(),
+            storage_usage_per_investor: Self::calculate_storage_usage_per_additional_investor()
+        }
+    }
+
+    /// The measuring is done in the separate registry, so it does not touch the stored investors on the state migration.
+    pub fn calculate_storage_usage_per_additional_investor() -> StorageUsage {
+        let mut investor_setting_registry: UnorderedMap<AccountId, InvestorSetting> = UnorderedMap::new(StorageKey::StorageUsageMeasurement);
+
+        let initial_storage_usage = env::storage_usage();
+
+        let account_id = get_account_id_with_maximum_length();
+
+        investor_setting_registry.insert(
+            &account_id,
+            &InvestorSetting {
+                maximum_staked_balance: Some(0),
+                allowed_validator_account_ids: None,
+                deposit_fee: Some(
+                    Fee {
+                        numerator: 0,
+                        denominator: 0
+                    }
+                ),
+                storage_staking_payer_account_id: account_id.clone(),
+                storage_staking_price: 0
+            }
+        );
+
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        investor_setting_registry.remove(&account_id);
+
+        storage_usage
+    }
+
+    pub fn initialize_investor_setting_registry() -> UnorderedMap<AccountId, InvestorSetting> {
+        UnorderedMap::new(StorageKey::InvestorSetting)
+    }
+
+    pub fn initialize_validator_proposal_registry() -> UnorderedMap<AccountId, ValidatorProposal> {
+        UnorderedMap::new(StorageKey::ValidatorProposal)
+    }
+}
@@ -0,0 +1,56 @@
+use near_sdk::{AccountId, Balance, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use std::mem::size_of;
+use super::fee::Fee;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InvestorSetting {
+    /// Maximum Near amount that the investor can stake on validators. There is no limit if it is not set.
+    pub maximum_staked_balance: Option<Balance>,
+    /// Validators on which the investor can stake. All validators are allowed if it is not set.
+    pub allowed_validator_account_ids: Option<Vec<AccountId>>,
+    /// Fee that the pool takes from the Near amount deposited by the investor on validator.
+    /// It is paid to the self fee receiver.
+    pub deposit_fee: Option<Fee>,
+    /// Account that paid the storage staking of the investor registration. It gets the storage staking back
+    /// when the investor leaves the investment or is removed.
+    pub storage_staking_payer_account_id: AccountId,
+    /// Storage staking price paid on the investor registration.
+    pub storage_staking_price: Balance
+}
+
+impl InvestorSetting {
+    pub fn new(storage_staking_payer_account_id: AccountId, storage_staking_price: Balance) -> Self {
+        Self {
+            maximum_staked_balance: None,
+            allowed_validator_account_ids: None,
+            deposit_fee: None,
+            storage_staking_payer_account_id,
+            storage_staking_price
+        }
+    }
+
+    pub fn is_validator_allowed(&self, validator_account_id: &AccountId) -> bool {
+        match self.allowed_validator_account_ids {
+            Some(ref allowed_validator_account_ids_) => allowed_validator_account_ids_.contains(validator_account_id),
+            None => true
+        }
+    }
+
+    /// Storage usage that is not covered by the storage staking price per investor.
+    /// In bytes.
+    pub fn calculate_additional_storage_usage(&self) -> StorageUsage {
+        match self.allowed_validator_account_ids {
+            Some(ref allowed_validator_account_ids_) => {
+                (
+                    size_of::<u32>()
+                    + allowed_validator_account_ids_
+                        .iter()
+                        .map(|validator_account_id| size_of::<u32>() + validator_account_id.as_str().len())
+                        .sum::<usize>()
+                ) as StorageUsage
+            }
+            None => 0
+        }
+    }
+}
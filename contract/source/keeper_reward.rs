@@ -0,0 +1,44 @@
+use near_sdk::{Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct KeeperReward {
+    /// Near amount available for paying rewards to the accounts who carry out the epoch maintenance.
+    pub budget: Balance,
+    /// Near amount paid for one successful maintenance call.
+    pub near_amount_per_call: Balance,
+    /// Maximum Near amount paid to all keepers in one epoch.
+    pub maximum_near_amount_per_epoch: Balance,
+    /// Near amount already paid to keepers in the epoch with current_epoch_height.
+    pub paid_near_amount_in_current_epoch: Balance,
+    pub current_epoch_height: EpochHeight
+}
+
+impl KeeperReward {
+    pub fn new(current_epoch_height: EpochHeight) -> Self {
+        Self {
+            budget: 0,
+            near_amount_per_call: 0,
+            maximum_near_amount_per_epoch: 0,
+            paid_near_amount_in_current_epoch: 0,
+            current_epoch_height
+        }
+    }
+
+    /// Reserves the reward for one maintenance call taking into account the budget and the limit per epoch.
+    pub fn take_near_amount_per_call(&mut self, current_epoch_height: EpochHeight) -> Balance {
+        if self.current_epoch_height != current_epoch_height {
+            self.current_epoch_height = current_epoch_height;
+            self.paid_near_amount_in_current_epoch = 0;
+        }
+
+        let near_amount = self.near_amount_per_call
+            .min(self.budget)
+            .min(self.maximum_near_amount_per_epoch.saturating_sub(self.paid_near_amount_in_current_epoch));
+
+        self.budget -= near_amount;
+        self.paid_near_amount_in_current_epoch += near_amount;
+
+        near_amount
+    }
+}
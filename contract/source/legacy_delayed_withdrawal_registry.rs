@@ -0,0 +1,17 @@
+use near_sdk::{AccountId, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use super::delayed_withdrawal::DelayedWithdrawal;
+
+/// Delayed withdrawals created before the independent delayed withdrawals of the account were added.
+/// Each account has at most one such delayed withdrawal, it is not represented as the non-fungible token
+/// and it can only be taken.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LegacyDelayedWithdrawalRegistry {
+    /// Storage.
+    /// AccountId - user account id.
+    pub delayed_withdrawal_registry: LookupMap<AccountId, DelayedWithdrawal>,
+    /// Storage usage that was paid by the account for the delayed withdrawal.
+    /// In bytes.
+    pub storage_usage_per_delayed_withdrawal: StorageUsage
+}
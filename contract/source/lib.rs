@@ -3,37 +3,288 @@ use near_sdk::{AccountId, Balance};
 
 pub mod stake_pool;
 mod account_balance;
+mod account_delayed_withdrawal;
 mod account_registry;
+mod config;
+mod council;
 mod cross_contract_call;
 mod data_transfer_object;
 mod delayed_withdrawal;
 mod delayed_withdrawn_fund;
+mod epoch_phase;
+mod event;
 mod fee_registry;
 mod fee;
 mod fund;
 mod fungible_token;
+mod investment_move;
 mod investment_withdrawal;
 mod investor_investment;
+mod investor_registry;
+mod investor_setting;
+mod keeper_reward;
+mod legacy_delayed_withdrawal_registry;
+mod liquidity_pool;
+mod pausable_operation;
+mod pause;
+mod proposal_action;
+mod proposal;
+mod redelegation;
 mod reward;
+mod role;
+mod role_registry;
 mod shared_fee;
 mod stake_decreasing_kind;
 mod staking_contract_version;
 mod storage_key;
 mod validating;
 mod validator_balance;
+mod validator_metadata;
+mod validator_performance;
+mod validator_proposal;
 mod validator;
+mod versioned_stake_pool;
 
-/// Quantity of epochs after passing which it is possible to withdraw fund.
-const EPOCH_QUANTITY_FOR_DELAYED_WITHDRAWAL: u64 = 8;
-/// Quantity of epochs after passing which it is possible to request unstake from validator.
-const EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE: u64 = 4;
-/// Minimum Gas amount for common transaction.
-const MINIMUM_NUMBER_OF_TGAS: u64 = 300;
-/// The minimum Near amount that must be attached to a transaction.
-const MINIMUN_DEPOSIT_AMOUNT: Balance = ONE_NEAR;
+/// Default quantity of epochs after passing which it is possible to withdraw fund.
+const DEFAULT_EPOCH_QUANTITY_FOR_DELAYED_WITHDRAWAL: u64 = 8;
+/// Default quantity of epochs after passing which it is possible to request unstake from validator.
+const DEFAULT_EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE: u64 = 4;
+/// Default minimum Gas amount for any transaction. The Gas amount required by the method itself is applied if it is greater.
+const DEFAULT_MINIMUM_NUMBER_OF_TGAS: u64 = NUMBER_OF_TGAS_FOR_EXECUTION;
+/// Maximum Gas amount that can be attached to a transaction.
+const MAXIMUM_NUMBER_OF_TGAS: u64 = 300;
+/// Gas amount for the execution of the method body, including creation of the transfer promises.
+const NUMBER_OF_TGAS_FOR_EXECUTION: u64 = 10;
+/// Gas amount for the fees of one function call promise and its result receipt, that are charged on top of the reserved Gas amount.
+const NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES: u64 = 10;
+/// Gas amount reserved for the validator `deposit_and_stake` call.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE: u64 = 35;
+/// Gas amount reserved for the validator `unstake` call.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE: u64 = 35;
+/// Gas amount reserved for the validator `withdraw` call.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_WITHDRAW: u64 = 20;
+/// Gas amount reserved for one validator balance request.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST: u64 = 5;
+/// Gas amount reserved for the validator reward fee fraction request.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_FEE_REQUEST: u64 = 5;
+/// Gas amount reserved for the callbacks that only change the pool state and transfer Near.
+const NUMBER_OF_TGAS_FOR_CALLBACK: u64 = 10;
+/// Gas amount reserved for the callbacks of the restaking promise chain.
+const NUMBER_OF_TGAS_FOR_DECREASE_VALIDATOR_STAKE_FOR_RESTAKING_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_REDELEGATE_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+/// Gas amount reserved for the validator unstake for restaking, that can be started in the validator update callbacks.
+const NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE: u64 =
+    NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE + NUMBER_OF_TGAS_FOR_DECREASE_VALIDATOR_STAKE_FOR_RESTAKING_CALLBACK + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+/// Gas amount reserved for one validator processing in the update validator callbacks.
+const NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING: u64 = 3;
+const NUMBER_OF_TGAS_FOR_DEPOSIT_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+const NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_2: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+/// The callback unstakes from the validator and schedules the second callback.
+const NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1: u64 =
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE + NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_2
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+/// The callback can restake the redelegated Near amount on the target validator.
+const NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK: u64 =
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_REDELEGATE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+/// The callback can unstake from the retiring or redelegating validator.
+const NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK: u64 =
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING + NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE;
+/// Base Gas amount of the batched validators update callback, the Gas amount for each validator is added on the call.
+const NUMBER_OF_TGAS_FOR_UPDATE_VALIDATORS_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+/// Gas amount reserved for one delayed withdrawal processing on taking.
+const NUMBER_OF_TGAS_FOR_DELAYED_WITHDRAWAL_PROCESSING: u64 = 1;
+const NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + MAXIMUM_QUANTITY_OF_DELAYED_WITHDRAWALS_PER_ACCOUNT * NUMBER_OF_TGAS_FOR_DELAYED_WITHDRAWAL_PROCESSING;
+/// The liquidity pool rebalancing takes the matured delayed withdrawals of the pool.
+const NUMBER_OF_TGAS_FOR_REBALANCE_LIQUIDITY_POOL: u64 = NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL;
+/// Gas amounts required by the methods with cross-contract calls.
+const NUMBER_OF_TGAS_FOR_DEPOSIT: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_DEPOSIT_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_WITHDRAW + NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_FEE_REQUEST + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+/// Gas amount reserved for the deployment of the new contract code.
+const NUMBER_OF_TGAS_FOR_CONTRACT_DEPLOYMENT: u64 = 50;
+/// Gas amount reserved for the state migration after the new contract code deployment.
+const NUMBER_OF_TGAS_FOR_MIGRATE: u64 = 50;
+const NUMBER_OF_TGAS_FOR_UPGRADE: u64 =
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_CONTRACT_DEPLOYMENT + NUMBER_OF_TGAS_FOR_MIGRATE + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+const NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK: u64 = NUMBER_OF_TGAS_FOR_CALLBACK;
+/// Version of the pool state layout. It must be increased with adding a new variant to the VersionedStakePool.
+const STATE_VERSION: u8 = 1;
+/// Maximum quantity of validators updated in one batched validators update.
+const MAXIMUM_QUANTITY_OF_VALIDATORS_IN_BATCH_UPDATE: u64 = 10;
+/// Maximum quantity of validators and investors verified on one page of the invariant verification.
+const MAXIMUM_QUANTITY_OF_ENTRIES_IN_INVARIANT_VERIFICATION: u64 = 50;
+/// Maximum quantity of not taken delayed withdrawals of one account, it limits the Gas amount for taking them.
+const MAXIMUM_QUANTITY_OF_DELAYED_WITHDRAWALS_PER_ACCOUNT: u64 = 16;
+/// Maximum quantity of validators in the list of the investor distributions passed to a method, it limits the Gas amount.
+const MAXIMUM_QUANTITY_OF_VALIDATORS_IN_DISTRIBUTION_LIST: u64 = 16;
+/// Maximum quantity of council members, it limits the pool state growth and the Gas amount for the proposal execution.
+const MAXIMUM_QUANTITY_OF_COUNCIL_MEMBERS: u64 = 16;
+/// Default minimum Near amount that must be attached to a transaction.
+const DEFAULT_MINIMUM_DEPOSIT_AMOUNT: Balance = ONE_NEAR;
 /// Maximum number of characters in account name, it is needed for storage staking calculating.
 const MAXIMUM_NUMBER_OF_CHARACTERS_IN_ACCOUNT_NAME: usize = 64;
+/// Maximum number of characters in validator display name.
+const MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_NAME: usize = 64;
+/// Maximum number of characters in validator URL.
+const MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_URL: usize = 256;
+/// Quantity of validator updates stored in the validator performance history.
+const QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE: u64 = 30;
+/// Approximate quantity of epochs in a year, it is needed for APY calculating.
+const EPOCH_QUANTITY_PER_YEAR: u64 = 730;
+
+// The Gas budgets are pinned at compile time: every method must fit into the maximum Gas amount of a transaction.
+const _: () = assert!(NUMBER_OF_TGAS_FOR_DEPOSIT <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL <= MAXIMUM_NUMBER_OF_TGAS);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_UPGRADE <= MAXIMUM_NUMBER_OF_TGAS);
+// The most expensive proposal action is the upgrade.
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_UPGRADE + NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= MAXIMUM_NUMBER_OF_TGAS
+);
+// The full batch with one restaking validator must fit, so the batch can always make progress.
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATORS_CALLBACK + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        + MAXIMUM_QUANTITY_OF_VALIDATORS_IN_BATCH_UPDATE
+            * (NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES + NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING)
+        + NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE
+        <= MAXIMUM_NUMBER_OF_TGAS
+);
+// Transactions without cross-contract calls must not require the maximum Gas amount.
+const _: () = assert!(NUMBER_OF_TGAS_FOR_EXECUTION < MAXIMUM_NUMBER_OF_TGAS);
+// The methods with cross-contract calls cover their own execution, the Gas amounts reserved for the promises and their fees.
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_DEPOSIT_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_DEPOSIT
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_WITHDRAW + NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_VALIDATOR_FEE_REQUEST + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION
+);
+const _: () = assert!(NUMBER_OF_TGAS_FOR_EXECUTION + NUMBER_OF_TGAS_FOR_MIGRATE + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES <= NUMBER_OF_TGAS_FOR_UPGRADE);
+// The callbacks with cross-contract calls cover their own execution, the Gas amounts reserved for the promises and their fees.
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE + NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_2
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE + NUMBER_OF_TGAS_FOR_REDELEGATE_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE + NUMBER_OF_TGAS_FOR_DECREASE_VALIDATOR_STAKE_FOR_RESTAKING_CALLBACK
+        + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        <= NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK
+);
+const _: () = assert!(
+    NUMBER_OF_TGAS_FOR_CALLBACK + NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING + NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE
+        <= NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK
+);
 
 fn get_account_id_with_maximum_length() -> AccountId {
     AccountId::new_unchecked("a".repeat(MAXIMUM_NUMBER_OF_CHARACTERS_IN_ACCOUNT_NAME))
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test {
+    use near_sdk::json_types::Base64VecU8;
+    use super::*;
+    use super::proposal_action::ProposalAction;
+
+    #[test]
+    fn gas_amounts_are_pinned() {
+        assert_eq!(NUMBER_OF_TGAS_FOR_DEPOSIT, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE, 110);
+        assert_eq!(NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE, 125);
+        assert_eq!(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR, 113);
+        assert_eq!(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION, 45);
+        assert_eq!(NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL, 26);
+        assert_eq!(NUMBER_OF_TGAS_FOR_REBALANCE_LIQUIDITY_POOL, 26);
+        assert_eq!(NUMBER_OF_TGAS_FOR_UPGRADE, 120);
+        assert_eq!(NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK, 75);
+        assert_eq!(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK, 78);
+        assert_eq!(NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE, 65);
+    }
+
+    #[test]
+    fn proposal_execution_reserves_gas_for_the_upgrade() {
+        let upgrade = ProposalAction::Upgrade {
+            code: Base64VecU8(vec![])
+        };
+        assert_eq!(upgrade.get_number_of_tgas(), NUMBER_OF_TGAS_FOR_UPGRADE);
+        assert!(
+            NUMBER_OF_TGAS_FOR_EXECUTION + upgrade.get_number_of_tgas() + NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK
+                + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES <= MAXIMUM_NUMBER_OF_TGAS
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use near_sdk::{env, AccountId, Balance, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use super::get_account_id_with_maximum_length;
+use super::storage_key::StorageKey;
+
+/// Near/token liquidity pool for the instant withdrawal. Near amount of the pool is stored in Fund::classic_liquidity_balance.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LiquidityPool {
+    /// Storage.
+    /// AccountId - liquidity provider account id.
+    /// Balance - share amount.
+    pub share_registry: LookupMap<AccountId, Balance>,
+    pub liquidity_providers_quantity: u64,
+    pub total_share_amount: Balance,
+    /// Token amount received from the instant withdrawals and not requested for the delayed withdrawal yet.
+    pub token_amount: Balance,
+    /// In bytes.
+    pub storage_usage_per_share: StorageUsage
+}
+
+impl LiquidityPool {
+    pub fn new() -> Self {
+        Self {
+            share_registry: Self::initialize_share_registry(),
+            liquidity_providers_quantity: 0,
+            total_share_amount: 0,
+            token_amount: 0,
+            storage_usage_per_share: Self::calculate_storage_usage_per_additional_share()
+        }
+    }
+
+    fn calculate_storage_usage_per_additional_share() -> StorageUsage {
+        let mut share_registry = Self::initialize_share_registry();
+
+        let initial_storage_usage = env::storage_usage();
+
+        let account_id = get_account_id_with_maximum_length();
+
+        share_registry.insert(&account_id, &0);
+
+        env::storage_usage() - initial_storage_usage
+    }
+
+    fn initialize_share_registry() -> LookupMap<AccountId, Balance> {
+        LookupMap::new(StorageKey::LiquidityProvider)
+    }
+}
@@ -0,0 +1,18 @@
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Group of operations that can be paused independently.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableOperation {
+    Deposit,
+    DepositOnValidator,
+    InstantWithdraw,
+    DelayedWithdraw,
+    TakeDelayedWithdrawal,
+    FtTransfer,
+    /// Operations of the pool with validators: stake increasing and decreasing, taking unstaked balance,
+    /// validators updating, redelegation and investment moves.
+    ValidatorOperations,
+    /// Liquidity adding and removing, and rebalancing of the liquidity pool.
+    LiquidityPool
+}
@@ -0,0 +1,63 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::pausable_operation::PausableOperation;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Pause {
+    pub is_deposit_paused: bool,
+    pub is_deposit_on_validator_paused: bool,
+    pub is_instant_withdraw_paused: bool,
+    pub is_delayed_withdraw_paused: bool,
+    pub is_take_delayed_withdrawal_paused: bool,
+    pub is_ft_transfer_paused: bool,
+    pub is_validator_operations_paused: bool,
+    pub is_liquidity_pool_paused: bool
+}
+
+impl Pause {
+    pub fn new() -> Self {
+        Self {
+            is_deposit_paused: false,
+            is_deposit_on_validator_paused: false,
+            is_instant_withdraw_paused: false,
+            is_delayed_withdraw_paused: false,
+            is_take_delayed_withdrawal_paused: false,
+            is_ft_transfer_paused: false,
+            is_validator_operations_paused: false,
+            is_liquidity_pool_paused: false
+        }
+    }
+
+    pub fn is_paused(&self, pausable_operation: PausableOperation) -> bool {
+        *self.get_flag(pausable_operation)
+    }
+
+    pub fn set_paused(&mut self, pausable_operation: PausableOperation, is_paused: bool) {
+        *self.get_flag_mut(pausable_operation) = is_paused;
+    }
+
+    fn get_flag(&self, pausable_operation: PausableOperation) -> &bool {
+        match pausable_operation {
+            PausableOperation::Deposit => &self.is_deposit_paused,
+            PausableOperation::DepositOnValidator => &self.is_deposit_on_validator_paused,
+            PausableOperation::InstantWithdraw => &self.is_instant_withdraw_paused,
+            PausableOperation::DelayedWithdraw => &self.is_delayed_withdraw_paused,
+            PausableOperation::TakeDelayedWithdrawal => &self.is_take_delayed_withdrawal_paused,
+            PausableOperation::FtTransfer => &self.is_ft_transfer_paused,
+            PausableOperation::ValidatorOperations => &self.is_validator_operations_paused,
+            PausableOperation::LiquidityPool => &self.is_liquidity_pool_paused
+        }
+    }
+
+    fn get_flag_mut(&mut self, pausable_operation: PausableOperation) -> &mut bool {
+        match pausable_operation {
+            PausableOperation::Deposit => &mut self.is_deposit_paused,
+            PausableOperation::DepositOnValidator => &mut self.is_deposit_on_validator_paused,
+            PausableOperation::InstantWithdraw => &mut self.is_instant_withdraw_paused,
+            PausableOperation::DelayedWithdraw => &mut self.is_delayed_withdraw_paused,
+            PausableOperation::TakeDelayedWithdrawal => &mut self.is_take_delayed_withdrawal_paused,
+            PausableOperation::FtTransfer => &mut self.is_ft_transfer_paused,
+            PausableOperation::ValidatorOperations => &mut self.is_validator_operations_paused,
+            PausableOperation::LiquidityPool => &mut self.is_liquidity_pool_paused
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use near_sdk::{AccountId, Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::proposal_action::ProposalAction;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Proposal {
+    pub proposer_account_id: AccountId,
+    pub action: ProposalAction,
+    /// Near amount paid by the proposer and attached to the action execution.
+    pub near_amount: Balance,
+    /// Storage staking price paid by the proposer for the proposal.
+    pub storage_staking_price: Balance,
+    /// Council members who confirmed the proposal. The proposer confirms the proposal on creating.
+    pub confirmation_registry: Vec<AccountId>,
+    /// Epoch from which the proposal can be executed. It is set when the threshold is reached.
+    pub executable_epoch_height: Option<EpochHeight>
+}
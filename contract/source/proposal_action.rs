@@ -0,0 +1,58 @@
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base64VecU8;
+use near_sdk::serde::{Deserialize, Serialize};
+use super::fee::Fee;
+use super::staking_contract_version::StakingContractVersion;
+use super::NUMBER_OF_TGAS_FOR_EXECUTION;
+use super::NUMBER_OF_TGAS_FOR_UPGRADE;
+
+/// Sensitive management action that is carried out only with the council approval while the council is active.
+/// Do not change the order of variants.
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalAction {
+    ChangeRewardFee {
+        reward_fee_self: Option<Fee>,
+        reward_fee_partner: Option<Fee>
+    },
+    ChangeInstantWithdrawFee {
+        instant_withdraw_fee_self: Option<Fee>,
+        instant_withdraw_fee_partner: Option<Fee>
+    },
+    AddValidator {
+        validator_account_id: AccountId,
+        staking_contract_version: StakingContractVersion,
+        is_only_for_investment: bool,
+        is_preferred: bool
+    },
+    RemoveValidator {
+        validator_account_id: AccountId
+    },
+    Upgrade {
+        code: Base64VecU8
+    },
+    ChangeCouncil {
+        member_account_ids: Vec<AccountId>,
+        threshold: u64,
+        execution_delay_epoch_quantity: u64
+    },
+    ApproveValidatorProposal {
+        validator_account_id: AccountId
+    },
+    ChangeFeeReceivers {
+        self_fee_receiver_account_id: AccountId,
+        partner_fee_receiver_account_id: AccountId,
+        is_token_sweeping: bool
+    }
+}
+
+impl ProposalAction {
+    /// Gas amount required by the pool method that carries out the action.
+    pub fn get_number_of_tgas(&self) -> u64 {
+        match self {
+            Self::Upgrade { .. } => NUMBER_OF_TGAS_FOR_UPGRADE,
+            _ => NUMBER_OF_TGAS_FOR_EXECUTION
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Redelegation {
+    /// Validator on which the classic stake should be moved.
+    pub target_validator_account_id: AccountId,
+    /// Classic Near amount that the pool manager requested to move.
+    pub near_amount: Balance,
+    /// Classic Near amount already unstaked from the source validator and waiting to be restaked.
+    pub unstaked_near_amount: Balance,
+    /// Id of account who spent funds on storage staking for this structure.
+    pub account_id: AccountId
+}
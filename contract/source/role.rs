@@ -0,0 +1,18 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// Management role. Each role can be held by several accounts. The pool manager has all roles except the guardian one.
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Clone, Copy, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Changes reward, instant withdraw and keeper reward fees.
+    FeeAdmin,
+    /// Adds, changes, retires and removes validators, redelegates the stake.
+    ValidatorAdmin,
+    /// Adds and removes investors.
+    InvestorAdmin,
+    /// Carries out the routine stake distribution.
+    Operator,
+    /// Only pauses operations.
+    Guardian
+}
@@ -0,0 +1,73 @@
+use near_sdk::{env, AccountId, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedSet;
+use super::get_account_id_with_maximum_length;
+use super::role::Role;
+use super::storage_key::StorageKey;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct RoleRegistry {
+    /// Storage.
+    /// AccountId - role holder account id.
+    pub fee_admin_registry: UnorderedSet<AccountId>,
+    pub validator_admin_registry: UnorderedSet<AccountId>,
+    pub investor_admin_registry: UnorderedSet<AccountId>,
+    pub operator_registry: UnorderedSet<AccountId>,
+    pub guardian_registry: UnorderedSet<AccountId>,
+    /// In bytes.
+    pub storage_usage_per_role_account: StorageUsage
+}
+
+impl RoleRegistry {
+    pub fn new() -> Self {
+        Self {
+            fee_admin_registry: Self::initialize_role_account_registry(Role::FeeAdmin),
+            validator_admin_registry: Self::initialize_role_account_registry(Role::ValidatorAdmin),
+            investor_admin_registry: Self::initialize_role_account_registry(Role::InvestorAdmin),
+            operator_registry: Self::initialize_role_account_registry(Role::Operator),
+            guardian_registry: Self::initialize_role_account_registry(Role::Guardian),
+            storage_usage_per_role_account: Self::calculate_storage_usage_per_additional_role_account()
+        }
+    }
+
+    pub fn get(&self, role: Role) -> &UnorderedSet<AccountId> {
+        match role {
+            Role::FeeAdmin => &self.fee_admin_registry,
+            Role::ValidatorAdmin => &self.validator_admin_registry,
+            Role::InvestorAdmin => &self.investor_admin_registry,
+            Role::Operator => &self.operator_registry,
+            Role::Guardian => &self.guardian_registry
+        }
+    }
+
+    pub fn get_mut(&mut self, role: Role) -> &mut UnorderedSet<AccountId> {
+        match role {
+            Role::FeeAdmin => &mut self.fee_admin_registry,
+            Role::ValidatorAdmin => &mut self.validator_admin_registry,
+            Role::InvestorAdmin => &mut self.investor_admin_registry,
+            Role::Operator => &mut self.operator_registry,
+            Role::Guardian => &mut self.guardian_registry
+        }
+    }
+
+    /// The measurement account is removed, so it is not listed among the role holders.
+    fn calculate_storage_usage_per_additional_role_account() -> StorageUsage {
+        let mut role_account_registry = Self::initialize_role_account_registry(Role::FeeAdmin);
+
+        let initial_storage_usage = env::storage_usage();
+
+        let account_id = get_account_id_with_maximum_length();
+
+        role_account_registry.insert(&account_id);
+
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        role_account_registry.remove(&account_id);
+
+        storage_usage
+    }
+
+    fn initialize_role_account_registry(role: Role) -> UnorderedSet<AccountId> {
+        UnorderedSet::new(StorageKey::RoleAccount { role })
+    }
+}
@@ -1,45 +1,125 @@
 use core::convert::Into;
 use near_contract_standards::fungible_token::core::FungibleTokenCore;
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
-use near_sdk::{env, near_bindgen, PanicOnDefault, AccountId, Balance, EpochHeight, Promise, PromiseResult, StorageUsage, Gas, PromiseOrValue};
+use near_contract_standards::non_fungible_token::core::NonFungibleTokenCore;
+use near_contract_standards::non_fungible_token::enumeration::NonFungibleTokenEnumeration;
+use near_contract_standards::non_fungible_token::events::{NftBurn, NftMint, NftTransfer};
+use near_contract_standards::non_fungible_token::metadata::TokenMetadata;
+use near_contract_standards::non_fungible_token::{Token, TokenId};
+use near_sdk::{assert_one_yocto, env, near_bindgen, PanicOnDefault, AccountId, Balance, EpochHeight, Promise, PromiseResult, StorageUsage, Gas, PromiseOrValue};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
 use super::account_balance::AccountBalance;
+use super::account_delayed_withdrawal::AccountDelayedWithdrawal;
 use super::account_registry::AccountRegistry;
+use super::config::Config;
+use super::council::Council;
 use super::cross_contract_call::classic_validator::classic_validator;
 use super::data_transfer_object::account_balance::AccountBalance as AccountBalanceDto;
+use super::data_transfer_object::account_registry::AccountRegistry as AccountRegistryDto;
 use super::data_transfer_object::aggregated::Aggregated;
 use super::data_transfer_object::base_account_balance::BaseAccountBalance;
 use super::data_transfer_object::callback_result::CallbackResult;
+use super::data_transfer_object::config::Config as ConfigDto;
+use super::data_transfer_object::council::Council as CouncilDto;
+use super::data_transfer_object::delayed_withdrawal::DelayedWithdrawal as DelayedWithdrawalDto;
 use super::data_transfer_object::delayed_withdrawal_details::DelayedWithdrawalDetails;
+use super::data_transfer_object::deposit_details::DepositDetails;
 use super::data_transfer_object::epoch_height_registry::EpochHeightRegistry;
+use super::data_transfer_object::epoch_phase_details::EpochPhaseDetails;
 use super::data_transfer_object::fee_registry_light::FeeRegistryLight;
 use super::data_transfer_object::full_for_account::FullForAccount;
 use super::data_transfer_object::full::Full;
 use super::data_transfer_object::fund::Fund as FundDto;
 use super::data_transfer_object::fungible_token_metadata::FungibleTokenMetadata as FungibleTokenMetadataDto;
 use super::data_transfer_object::investment_account_balance::InvestmentAccountBalance;
+use super::data_transfer_object::investment_move::InvestmentMove as InvestmentMoveDto;
+use super::data_transfer_object::invariant_report::{Discrepancy, Invariant, InvariantReport};
 use super::data_transfer_object::investor_investment::InvestorInvestment as InvestorInvestmentDto;
+use super::data_transfer_object::investor::Investor as InvestorDto;
+use super::data_transfer_object::keeper_reward::KeeperReward as KeeperRewardDto;
+use super::data_transfer_object::liquidity_pool::LiquidityPool as LiquidityPoolDto;
+use super::data_transfer_object::liquidity_provider_share::LiquidityProviderShare as LiquidityProviderShareDto;
+use super::data_transfer_object::pause::Pause as PauseDto;
+use super::data_transfer_object::proposal::Proposal as ProposalDto;
+use super::data_transfer_object::redelegation::Redelegation as RedelegationDto;
 use super::data_transfer_object::requested_to_withdrawal_fund::RequestedToWithdrawalFund;
 use super::data_transfer_object::storage_staking_price::StorageStakingPrice;
 use super::data_transfer_object::storage_staking_requested_coverage::StorageStakingRequestedCoverage;
+use super::data_transfer_object::validator_performance::EpochReward as EpochRewardDto;
+use super::data_transfer_object::validator_performance::ValidatorPerformance as ValidatorPerformanceDto;
 use super::data_transfer_object::validator::Validator as ValidatorDto;
+use super::data_transfer_object::validator_proposal::ValidatorProposal as ValidatorProposalDto;
 use super::delayed_withdrawal::DelayedWithdrawal;
-use super::EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE;
+use super::epoch_phase::EpochPhase;
+use super::event::{Event, PauseData, ProposalData, RoleData};
 use super::fee_registry::FeeRegistry;
 use super::fee::Fee;
 use super::fund::Fund;
 use super::fungible_token::FungibleToken;
+use super::investment_move::InvestmentMove;
 use super::investment_withdrawal::InvestmentWithdrawal;
 use super::investor_investment::InvestorInvestment;
-use super::MINIMUM_NUMBER_OF_TGAS;
-use super::MINIMUN_DEPOSIT_AMOUNT;
+use super::investor_registry::InvestorRegistry;
+use super::investor_setting::InvestorSetting;
+use super::keeper_reward::KeeperReward;
+use super::liquidity_pool::LiquidityPool;
+use super::pausable_operation::PausableOperation;
+use super::pause::Pause;
+use super::proposal_action::ProposalAction;
+use super::proposal::Proposal;
+use super::redelegation::Redelegation;
+use super::MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_NAME;
+use super::MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_URL;
+use super::MAXIMUM_QUANTITY_OF_DELAYED_WITHDRAWALS_PER_ACCOUNT;
+use super::MAXIMUM_QUANTITY_OF_ENTRIES_IN_INVARIANT_VERIFICATION;
+use super::MAXIMUM_QUANTITY_OF_VALIDATORS_IN_BATCH_UPDATE;
+use super::MAXIMUM_QUANTITY_OF_VALIDATORS_IN_DISTRIBUTION_LIST;
+use super::NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE;
+use super::NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_DECREASE_VALIDATOR_STAKE_FOR_RESTAKING_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_DEPOSIT;
+use super::NUMBER_OF_TGAS_FOR_DEPOSIT_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR;
+use super::NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_EXECUTION;
+use super::NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+use super::NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE;
+use super::NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_MIGRATE;
+use super::NUMBER_OF_TGAS_FOR_REBALANCE_LIQUIDITY_POOL;
+use super::NUMBER_OF_TGAS_FOR_REDELEGATE_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE;
+use super::NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1;
+use super::NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_2;
+use super::NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE;
+use super::NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL;
+use super::NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE;
+use super::NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR;
+use super::NUMBER_OF_TGAS_FOR_UPDATE_VALIDATORS_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION;
+use super::NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION_CALLBACK;
+use super::NUMBER_OF_TGAS_FOR_UPGRADE;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_FEE_REQUEST;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING;
+use super::NUMBER_OF_TGAS_FOR_VALIDATOR_WITHDRAW;
 use super::reward::Reward;
+use super::role::Role;
+use super::role_registry::RoleRegistry;
 use super::shared_fee::SharedFee;
 use super::stake_decreasing_kind::StakeDecreasingType;
 use super::staking_contract_version::StakingContractVersion;
 use super::validating::Validating;
 use super::validator::Validator;
+use super::validator_performance::EpochReward;
+use super::validator_proposal::ValidatorProposal;
+use super::versioned_stake_pool::{StakePoolV1, VersionedStakePool};
 use uint::construct_uint;
 
 construct_uint! {
@@ -57,7 +137,14 @@ pub struct StakePool {
     fee_registry: FeeRegistry,
     validating: Validating,
     current_epoch_height: EpochHeight,
-    reward: Reward
+    reward: Reward,
+    keeper_reward: KeeperReward,
+    config: Config,
+    liquidity_pool: LiquidityPool,
+    pause: Pause,
+    role_registry: RoleRegistry,
+    council: Council,
+    investor_registry: InvestorRegistry
 }
 
 #[near_bindgen]
@@ -90,6 +177,21 @@ impl StakePool {
         )
     }
 
+    /// Provides the ability to deploy the new contract code passed as the input and to migrate the state.
+    /// Available only for pool owner.
+    /// While the council is active, available only for council proposal.
+    pub fn upgrade(&mut self) -> Promise {
+        self.internal_upgrade()
+    }
+
+    /// Provides the ability to migrate the state of any previous version to the current version after the upgrade.
+    /// Available only for pool.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        Self::internal_migrate()
+    }
+
     /// Provides the ability to stake into pool.
     /// Available for all users.
     #[payable]
@@ -105,17 +207,19 @@ impl StakePool {
     }
 
     /// Provides the ability to instant unstake.
+    /// The fee of the swap from the liquidity pool stays in the liquidity pool and is earned by the liquidity providers,
+    /// the fee receivers get the fee only when the classic unstaked balance is used.
     /// Available for all users.
     #[payable]
     pub fn instant_withdraw(&mut self, token_amount: U128) -> Promise {
         self.internal_instant_withdraw(token_amount.into())
     }
 
-    /// Provides the ability to delayed unstake.
+    /// Provides the ability to delayed unstake. The delayed withdrawal can be created for another account.
     /// Available for all users.
     #[payable]
-    pub fn delayed_withdraw(&mut self, token_amount: U128) -> PromiseOrValue<()> {
-        self.internal_delayed_withdraw(token_amount.into())
+    pub fn delayed_withdraw(&mut self, token_amount: U128, receiver_id: Option<AccountId>) -> PromiseOrValue<()> {
+        self.internal_delayed_withdraw(token_amount.into(), receiver_id)
     }
 
     /// Delayed unstake process directly from validator.
@@ -125,51 +229,123 @@ impl StakePool {
         self.internal_delayed_withdraw_from_validator(near_amount.into(), validator_account_id)
     }
 
+    /// Provides the ability to delayed unstake the whole investor stake from all validators and to stop being investor.
+    /// All validators with the investor distributions must be listed, including the validators that are not registered anymore.
+    /// Available only for investor.
+    #[payable]
+    pub fn leave_investment(&mut self, validator_account_ids: Vec<AccountId>) -> PromiseOrValue<()> {
+        self.internal_leave_investment(validator_account_ids)
+    }
+
     /// Provides the ability to take unstaked balance after passing the delayed unstake process.
+    /// The unstaked balance can be sent to another account.
+    /// Available for all users.
+    #[payable]
+    pub fn take_delayed_withdrawal(&mut self, receiver_id: Option<AccountId>) -> Promise {
+        self.internal_take_delayed_withdrawal(receiver_id)
+    }
+
+    /// Provides the ability to send unstaked balance to the delayed withdrawal owner after passing the delayed unstake process.
+    /// Available for all users.
+    pub fn take_delayed_withdrawal_for(&mut self, account_id: AccountId) -> Promise {
+        self.internal_take_delayed_withdrawal_for(account_id)
+    }
+
+    /// Provides the ability to provide Near liquidity for the instant withdrawal and receive liquidity pool shares.
     /// Available for all users.
     #[payable]
-    pub fn take_delayed_withdrawal(&mut self) -> Promise {
-        self.internal_take_delayed_withdrawal()
+    pub fn add_liquidity(&mut self, near_amount: U128) -> PromiseOrValue<()> {
+        self.internal_add_liquidity(near_amount.into())
+    }
+
+    /// Provides the ability to exchange liquidity pool shares back to Near and, if the Near part of the pool is not enough, to tokens.
+    /// Available for all users.
+    #[payable]
+    pub fn remove_liquidity(&mut self, share_amount: U128) -> Promise {
+        self.internal_remove_liquidity(share_amount.into())
+    }
+
+    /// Provides the ability to take the matured delayed withdrawals of the liquidity pool and to turn the tokens accumulated
+    /// by the liquidity pool into the delayed withdrawal.
+    /// Available for all users.
+    pub fn rebalance_liquidity_pool(&mut self) {
+        self.internal_rebalance_liquidity_pool();
+    }
+
+    /// Provides the ability to move the investor stake from one validator to another
+    /// without withdrawing it from the pool.
+    /// Available only for investor.
+    #[payable]
+    pub fn move_investment(
+        &mut self,
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: U128
+    ) -> PromiseOrValue<()> {
+        self.internal_move_investment(source_validator_account_id, target_validator_account_id, near_amount.into())
+    }
+
+    /// Provides the ability to restake the moved investor stake on the target validator
+    /// after passing the delayed unstake process.
+    /// Available for investor, operator and pool manager.
+    pub fn complete_investment_move(&mut self, investor_account_id: AccountId) -> Promise {
+        self.internal_complete_investment_move(investor_account_id)
     }
 
     /// Provides the ability to stake via pool directly to the validator.
-    /// Available only for pool manager.
+    /// Available only for pool manager and operator.
     pub fn increase_validator_stake(&mut self, validator_account_id: AccountId, near_amount: U128) -> Promise {
         self.internal_increase_validator_stake(validator_account_id, near_amount.into())
     }
 
     /// Provides the ability to unstake from validator for the needs of delayed withdrawal fund.
-    /// Available only for pool manager.
-    pub fn requested_decrease_validator_stake(
-        &mut self,
-        validator_account_id: AccountId,
-        near_amount: U128,
-        stake_decreasing_type: StakeDecreasingType
-    ) -> Promise {
-        self.internal_requested_decrease_validator_stake(validator_account_id, near_amount.into(), stake_decreasing_type)
+    /// The Near amount is the requested Near amount that the validator stake can cover.
+    /// Available for all users.
+    pub fn requested_decrease_validator_stake(&mut self, validator_account_id: AccountId, stake_decreasing_type: StakeDecreasingType) -> Promise {
+        self.internal_requested_decrease_validator_stake(validator_account_id, stake_decreasing_type)
     }
 
     /// Provides the ability to withdraw unstaked balance from validator for the needs of delayed withdrawal fund.
-    /// Available only for pool manager.
+    /// Available for all users.
     pub fn take_unstaked_balance(&mut self, validator_account_id: AccountId) -> Promise {
         self.internal_take_unstaked_balance(validator_account_id)
     }
 
     /// Provides the ability to update validator state.
-    /// Available only for pool manager.
+    /// Available for all users.
     pub fn update_validator(&mut self, validator_account_id: AccountId) -> Promise {
         self.internal_update_validator(validator_account_id)
     }
 
+    /// Provides the ability to update the state of several validators in one transaction.
+    /// Available for all users.
+    pub fn update_validators(&mut self, from_index: Option<u64>, limit: Option<u64>) -> Promise {
+        self.internal_update_validators(from_index, limit)
+    }
+
     /// Provides the ability to update pool state. Must be used after 'updated_validator'
     /// for each validator.
-    /// Available only for pool manager.
+    /// Available for all users.
     pub fn update(&mut self) {
         self.internal_update();
     }
 
+    /// Provides the ability to replenish the budget for rewarding the accounts who carry out the epoch maintenance.
+    /// Available for all users.
+    #[payable]
+    pub fn fund_keeper_budget(&mut self) {
+        self.internal_fund_keeper_budget();
+    }
+
+    /// Provides the ability to change the reward for the epoch maintenance.
+    /// Available only for pool manager and fee admin.
+    pub fn change_keeper_reward(&mut self, near_amount_per_call: U128, maximum_near_amount_per_epoch: U128) {
+        self.internal_change_keeper_reward(near_amount_per_call.into(), maximum_near_amount_per_epoch.into());
+    }
+
     /// Provides the ability to add validator.
-    /// Available only for pool manager.
+    /// Available only for pool manager and validator admin.
+    /// While the council is active, available only for council proposal.
     #[payable]
     pub fn add_validator(
         &mut self,
@@ -178,70 +354,287 @@ impl StakePool {
         is_only_for_investment: bool,
         is_preferred: bool
     ) -> PromiseOrValue<()> {
-        self.internal_add_validator(validator_account_id, staking_contract_version, is_only_for_investment, is_preferred)
+        self.internal_add_validator(validator_account_id, staking_contract_version, is_only_for_investment, is_preferred, env::predecessor_account_id())
     }
 
     /// Provides the ability to change validator state in context of in investment.
-    /// Available only for pool manager.
+    /// Available only for pool manager and validator admin.
     pub fn change_validator_investment_context(&mut self, validator_account_id: AccountId, is_only_for_investment: bool) {
         self.internal_change_validator_investment_context(validator_account_id, is_only_for_investment);
     }
 
     /// Provides the ability to change preffered validator.
-    /// Available only for pool manager.
+    /// Available only for pool manager and validator admin.
     pub fn change_preffered_validator(&mut self, validator_account_id: Option<AccountId>) {
         self.internal_change_preffered_validator(validator_account_id);
     }
 
+    /// Provides the ability to change the minimum validator score required for staking on the validator.
+    /// Available only for pool manager and validator admin.
+    pub fn change_minimum_validator_score(&mut self, minimum_validator_score: Option<u64>) {
+        self.internal_change_minimum_validator_score(minimum_validator_score);
+    }
+
+    /// Provides the ability to change validator metadata.
+    /// Available only for pool manager and validator admin.
+    #[payable]
+    pub fn change_validator_metadata(
+        &mut self,
+        validator_account_id: AccountId,
+        name: Option<String>,
+        url: Option<String>,
+        commission: Option<Fee>
+    ) -> PromiseOrValue<()> {
+        self.internal_change_validator_metadata(validator_account_id, name, url, commission)
+    }
+
+    /// Provides the ability to fetch validator commission from validator.
+    /// Available only for pool manager and operator.
+    pub fn update_validator_commission(&mut self, validator_account_id: AccountId) -> Promise {
+        self.internal_update_validator_commission(validator_account_id)
+    }
+
+    /// Provides the ability to move classic stake from one validator to another.
+    /// The stake is unstaked in the right epoch and restaked on the target validator after the delay.
+    /// Available only for pool manager and validator admin.
+    #[payable]
+    pub fn redelegate(
+        &mut self,
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: U128
+    ) -> PromiseOrValue<()> {
+        self.internal_redelegate(source_validator_account_id, target_validator_account_id, near_amount.into())
+    }
+
+    /// Provides the ability to start withdrawing the validator from the pool.
+    /// Available only for pool manager and validator admin.
+    pub fn retire_validator(&mut self, validator_account_id: AccountId) {
+        self.internal_retire_validator(validator_account_id);
+    }
+
     /// Provides the ability to remove validator.
-    /// Available only for pool manager.
+    /// Available only for pool manager and validator admin.
+    /// While the council is active, available only for council proposal.
     pub fn remove_validator(&mut self, validator_account_id: AccountId) -> Promise {
         self.internal_remove_validator(validator_account_id)
     }
 
+    /// Provides the ability to propose the validator that is added only for investment after the approval.
+    /// Available only for investor.
+    #[payable]
+    pub fn propose_validator(&mut self, validator_account_id: AccountId, staking_contract_version: StakingContractVersion) -> PromiseOrValue<()> {
+        self.internal_propose_validator(validator_account_id, staking_contract_version)
+    }
+
+    /// Available only for investor who proposed the validator.
+    pub fn cancel_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.internal_cancel_validator_proposal(validator_account_id)
+    }
+
+    /// Provides the ability to add the validator proposed by the investor.
+    /// Available only for pool manager and validator admin.
+    /// While the council is active, available only for council proposal.
+    pub fn approve_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.internal_approve_validator_proposal(validator_account_id)
+    }
+
+    /// Available only for pool manager and validator admin.
+    pub fn reject_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.internal_reject_validator_proposal(validator_account_id)
+    }
+
     /// Provides the ability to add investor.
-    /// Available only for pool manager.
+    /// Available only for pool manager and investor admin.
     #[payable]
     pub fn add_investor(&mut self, investor_account_id: AccountId) -> PromiseOrValue<()> {
         self.internal_add_investor(investor_account_id)
     }
 
     /// Provides the ability to add investor.
-    /// Available only for pool manager.
-    pub fn remove_investor(&mut self, investor_account_id: AccountId) -> Promise {
-        self.internal_remove_investor(investor_account_id)
+    /// Available only for pool manager and investor admin.
+    /// All validators with the investor distributions must be listed, including the validators that are not registered anymore.
+    pub fn remove_investor(&mut self, investor_account_id: AccountId, validator_account_ids: Vec<AccountId>) -> Promise {
+        self.internal_remove_investor(investor_account_id, validator_account_ids)
+    }
+
+    /// Provides the ability to change the limits of the investor deposits on validators.
+    /// Available only for pool manager and investor admin.
+    #[payable]
+    pub fn change_investor_setting(
+        &mut self,
+        investor_account_id: AccountId,
+        maximum_staked_balance: Option<U128>,
+        allowed_validator_account_ids: Option<Vec<AccountId>>,
+        deposit_fee: Option<Fee>
+    ) -> PromiseOrValue<()> {
+        self.internal_change_investor_setting(
+            investor_account_id,
+            maximum_staked_balance.map(|maximum_staked_balance_| maximum_staked_balance_.into()),
+            allowed_validator_account_ids,
+            deposit_fee
+        )
+    }
+
+    /// Provides the ability to propose the new pool owner. The proposed account becomes the owner only after accepting.
+    /// Available only for pool owner.
+    pub fn propose_owner(&mut self, owner_id: AccountId) {
+        self.internal_propose_owner(owner_id);
+    }
+
+    /// Available only for proposed pool owner.
+    pub fn accept_owner(&mut self) {
+        self.internal_accept_owner();
+    }
+
+    /// Available only for pool owner.
+    pub fn cancel_owner_proposal(&mut self) {
+        self.internal_cancel_owner_proposal();
+    }
+
+    /// Provides the ability to propose the new pool manager. The proposed account becomes the manager only after accepting.
+    /// Available only for pool owner and manager.
+    pub fn propose_manager(&mut self, manager_id: AccountId) {
+        self.internal_propose_manager(manager_id);
+    }
+
+    /// Available only for proposed pool manager.
+    pub fn accept_manager(&mut self) {
+        self.internal_accept_manager();
     }
 
-    /// Provides the ability to change pool manager.
     /// Available only for pool owner and manager.
-    pub fn change_manager(&mut self, manager_id: AccountId) {
-        self.internal_change_manager(manager_id);
+    pub fn cancel_manager_proposal(&mut self) {
+        self.internal_cancel_manager_proposal();
     }
 
     /// Provides the ability to change reward fee.
-    /// Available only for pool manager.
+    /// Available only for pool manager and fee admin.
+    /// While the council is active, available only for council proposal.
     pub fn change_reward_fee(&mut self, reward_fee_self: Option<Fee>, reward_fee_partner: Option<Fee>) {
         self.internal_change_reward_fee(reward_fee_self, reward_fee_partner);
     }
 
     /// Provides the ability to change fee for instant unstake process.
-    /// Available only for pool manager.
+    /// Available only for pool manager and fee admin.
+    /// While the council is active, available only for council proposal.
     pub fn change_instant_withdraw_fee(&mut self, instant_withdraw_fee_self: Option<Fee>, instant_withdraw_fee_partner: Option<Fee>) {
         self.internal_change_instant_withdraw_fee(instant_withdraw_fee_self, instant_withdraw_fee_partner);
     }
 
+    /// Provides the ability to change fee receivers. Previous fee receivers lose the protection from the removal
+    /// and can optionally give their token balance to the new fee receivers.
+    /// Available only for pool owner.
+    /// While the council is active, available only for council proposal.
+    #[payable]
+    pub fn change_fee_receivers(
+        &mut self,
+        self_fee_receiver_account_id: AccountId,
+        partner_fee_receiver_account_id: AccountId,
+        is_token_sweeping: bool
+    ) -> PromiseOrValue<()> {
+        self.internal_change_fee_receivers(
+            self_fee_receiver_account_id, partner_fee_receiver_account_id, is_token_sweeping, env::predecessor_account_id()
+        )
+    }
+
+    /// Provides the ability to change epoch quantities of the delayed withdrawal and validator unstake processes.
+    /// Available only for pool owner.
+    pub fn change_epoch_quantities(&mut self, epoch_quantity_for_delayed_withdrawal: u64, epoch_quantity_for_validator_unstake: u64) {
+        self.internal_change_epoch_quantities(epoch_quantity_for_delayed_withdrawal, epoch_quantity_for_validator_unstake);
+    }
+
+    /// Provides the ability to change minimum Gas amount for common transaction.
+    /// Available only for pool owner.
+    pub fn change_minimum_number_of_tgas(&mut self, minimum_number_of_tgas: u64) {
+        self.internal_change_minimum_number_of_tgas(minimum_number_of_tgas);
+    }
+
+    /// Provides the ability to change minimum Near amount that must be attached to a transaction.
+    /// Available only for pool owner.
+    pub fn change_minimum_deposit_amount(&mut self, minimum_deposit_amount: U128) {
+        self.internal_change_minimum_deposit_amount(minimum_deposit_amount.into());
+    }
+
+    /// Provides the ability to give the role to the account.
+    /// Available only for pool owner.
+    #[payable]
+    pub fn grant_role(&mut self, role: Role, account_id: AccountId) -> PromiseOrValue<()> {
+        self.internal_grant_role(role, account_id)
+    }
+
+    /// Available only for pool owner.
+    pub fn revoke_role(&mut self, role: Role, account_id: AccountId) -> Promise {
+        self.internal_revoke_role(role, account_id)
+    }
+
+    /// Provides the ability to stop the operation during an incident. Callbacks of the operation keep working.
+    /// Available only for pool owner or guardian.
+    pub fn pause(&mut self, operation: PausableOperation) {
+        self.internal_pause(operation);
+    }
+
+    /// Available only for pool owner.
+    pub fn unpause(&mut self, operation: PausableOperation) {
+        self.internal_unpause(operation);
+    }
+
+    /// Provides the ability to change the council members, the quantity of member confirmations required to execute
+    /// the proposal and the execution delay. The zero threshold deactivates the council.
+    /// Available only for pool owner while the council is not active and only for council proposal while the council is active.
+    pub fn change_council(&mut self, member_account_ids: Vec<AccountId>, threshold: u64, execution_delay_epoch_quantity: u64) {
+        self.internal_change_council(member_account_ids, threshold, execution_delay_epoch_quantity);
+    }
+
+    /// Provides the ability to propose the sensitive management action. The proposal is confirmed by the proposer.
+    /// Available only for council member.
+    #[payable]
+    pub fn create_proposal(&mut self, action: ProposalAction) -> PromiseOrValue<()> {
+        self.internal_create_proposal(action)
+    }
+
+    /// Provides the ability to confirm the proposal. The proposal is executed at once if the threshold is reached
+    /// and there is no execution delay.
+    /// Available only for council member.
+    #[payable]
+    pub fn confirm_proposal(&mut self, proposal_id: u64) -> PromiseOrValue<()> {
+        self.internal_confirm_proposal(proposal_id)
+    }
+
+    /// Provides the ability to execute the confirmed proposal after passing the execution delay.
+    /// Available for all users.
+    pub fn execute_proposal(&mut self, proposal_id: u64) -> Promise {
+        self.internal_execute_proposal(proposal_id)
+    }
+
+    /// Available only for proposer.
+    pub fn cancel_proposal(&mut self, proposal_id: u64) -> Promise {
+        self.internal_cancel_proposal(proposal_id)
+    }
+
     /// Provides the ability to change state of fund.
-    /// Available only for pool manager.
+    /// Available only for pool manager and operator.
     pub fn confirm_stake_distribution(&mut self) {
         self.internal_confirm_stake_distribution();
     }
 
+    /// Provides the ability to check the pool invariants on the page of the validators and listed investors, for example, after the upgrade.
+    /// Panics if some invariant is broken.
+    /// Available for all users.
+    pub fn assert_invariants(&mut self, from_index: Option<u64>, limit: Option<u64>) {
+        self.internal_assert_invariants(from_index, limit);
+    }
+
     /// View-methods:
 
     pub fn get_delayed_withdrawal_details(&self, account_id: AccountId) -> Option<DelayedWithdrawalDetails> {
         self.internal_get_delayed_withdrawal_details(account_id)
     }
 
+    pub fn get_delayed_withdrawals(&self, account_id: AccountId) -> Vec<DelayedWithdrawalDto> {
+        self.internal_get_delayed_withdrawals(account_id)
+    }
+
     pub fn get_account_balance(&self, account_id: AccountId) -> AccountBalanceDto {
         self.internal_get_account_balance(account_id)
     }
@@ -258,6 +651,34 @@ impl StakePool {
         self.internal_get_storage_staking_price()
     }
 
+    pub fn get_config(&self) -> ConfigDto {
+        self.internal_get_config()
+    }
+
+    pub fn get_pause(&self) -> PauseDto {
+        self.internal_get_pause()
+    }
+
+    pub fn get_account_registry(&self) -> AccountRegistryDto {
+        self.internal_get_account_registry()
+    }
+
+    pub fn get_role_accounts(&self, role: Role, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        self.internal_get_role_accounts(role, from_index, limit)
+    }
+
+    pub fn has_role(&self, account_id: AccountId, role: Role) -> bool {
+        self.internal_has_role(account_id, role)
+    }
+
+    pub fn get_council(&self) -> CouncilDto {
+        self.internal_get_council()
+    }
+
+    pub fn get_proposals(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ProposalDto> {
+        self.internal_get_proposals(from_index, limit)
+    }
+
     pub fn get_storage_staking_requested_coverage(&self, account_id: AccountId) -> StorageStakingRequestedCoverage {
         self.internal_get_storage_staking_requested_coverage(account_id)
     }
@@ -266,6 +687,18 @@ impl StakePool {
         self.internal_get_fund()
     }
 
+    pub fn get_liquidity_pool(&self) -> LiquidityPoolDto {
+        self.internal_get_liquidity_pool()
+    }
+
+    pub fn get_liquidity_provider_share(&self, account_id: AccountId) -> Option<LiquidityProviderShareDto> {
+        self.internal_get_liquidity_provider_share(account_id)
+    }
+
+    pub fn get_keeper_reward(&self) -> KeeperRewardDto {
+        self.internal_get_keeper_reward()
+    }
+
     pub fn get_fee_registry(&self) -> FeeRegistry {
         self.internal_get_fee_registry()
     }
@@ -278,6 +711,10 @@ impl StakePool {
         self.internal_get_current_epoch_height()
     }
 
+    pub fn get_epoch_phase(&self, from_index: Option<u64>, limit: Option<u64>) -> EpochPhaseDetails {
+        self.internal_get_epoch_phase(from_index, limit)
+    }
+
     pub fn is_stake_distributed(&self) -> bool {
         self.internal_is_stake_distributed()
     }
@@ -286,18 +723,38 @@ impl StakePool {
         self.internal_get_investor_investment(account_id)
     }
 
-    pub fn get_validator_registry(&self) -> Vec<ValidatorDto> {
-        self.internal_get_validator_registry()
+    pub fn get_investors(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<InvestorDto> {
+        self.internal_get_investors(from_index, limit)
+    }
+
+    pub fn get_investor(&self, account_id: AccountId) -> Option<InvestorDto> {
+        self.internal_get_investor(account_id)
+    }
+
+    pub fn get_validator_registry(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ValidatorDto> {
+        self.internal_get_validator_registry(from_index, limit)
     }
 
     pub fn get_preffered_validator(&self) -> Option<ValidatorDto> {
         self.internal_get_preffered_validator()
     }
 
+    pub fn get_validator_proposals(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ValidatorProposalDto> {
+        self.internal_get_validator_proposals(from_index, limit)
+    }
+
+    pub fn get_validator_performance(&self, validator_account_id: AccountId) -> Option<ValidatorPerformanceDto> {
+        self.internal_get_validator_performance(validator_account_id)
+    }
+
     pub fn get_aggregated(&self) -> Aggregated {
         self.internal_get_aggregated()
     }
 
+    pub fn verify_invariants(&self, from_index: Option<u64>, limit: Option<u64>) -> InvariantReport {
+        self.internal_verify_invariants(from_index, limit)
+    }
+
     pub fn get_requested_to_withdrawal_fund(&self) -> RequestedToWithdrawalFund {
         self.internal_get_requested_to_withdrawal_fund()
     }
@@ -338,6 +795,53 @@ impl FungibleTokenCore for StakePool {
     }
 }
 
+#[near_bindgen]
+impl NonFungibleTokenCore for StakePool {
+    /// Transfers the delayed withdrawal to another account.
+    #[payable]
+    fn nft_transfer(&mut self, receiver_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>) {
+        self.internal_nft_transfer(receiver_id, token_id, approval_id, memo);
+    }
+
+    #[payable]
+    fn nft_transfer_call(
+        &mut self,
+        _receiver_id: AccountId,
+        _token_id: TokenId,
+        _approval_id: Option<u64>,
+        _memo: Option<String>,
+        _msg: String
+    ) -> PromiseOrValue<bool> {
+        env::panic_str("Not supported.");
+    }
+
+    fn nft_token(&self, token_id: TokenId) -> Option<Token> {
+        self.internal_nft_token(token_id)
+    }
+}
+
+#[near_bindgen]
+impl NonFungibleTokenEnumeration for StakePool {
+    fn nft_total_supply(&self) -> U128 {
+        (self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry.len() as Balance).into()
+    }
+
+    fn nft_tokens(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<Token> {
+        self.internal_nft_tokens(from_index, limit)
+    }
+
+    fn nft_supply_for_owner(&self, account_id: AccountId) -> U128 {
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&account_id) {
+            Some(account_delayed_withdrawal) => (account_delayed_withdrawal.delayed_withdrawal_registry.len() as Balance).into(),
+            None => 0.into()
+        }
+    }
+
+    fn nft_tokens_for_owner(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>) -> Vec<Token> {
+        self.internal_nft_tokens_for_owner(account_id, from_index, limit)
+    }
+}
+
 impl StakePool {
     fn internal_new(
         fungible_token_metadata: FungibleTokenMetadataDto,
@@ -425,6 +929,8 @@ impl StakePool {
             account_registry: AccountRegistry {
                 owner_id: predecessor_account_id.clone(),
                 manager_id: manager_id_,
+                pending_owner_id: None,
+                pending_manager_id: None,
                 self_fee_receiver_account_id,
                 partner_fee_receiver_account_id
             },
@@ -439,19 +945,85 @@ impl StakePool {
             reward: Reward {
                 previous_epoch_rewards_from_validators_near_amount: 0,
                 total_rewards_from_validators_near_amount: 0
-            }
+            },
+            keeper_reward: KeeperReward::new(env::epoch_height()),
+            config: Config::new(),
+            liquidity_pool: LiquidityPool::new(),
+            pause: Pause::new(),
+            role_registry: RoleRegistry::new(),
+            council: Council::new(),
+            investor_registry: InvestorRegistry::new()
         };
         stake_pool.fungible_token.account_registry.insert(&stake_pool.account_registry.self_fee_receiver_account_id, &account_balance);
         stake_pool.fungible_token.account_registry.insert(&stake_pool.account_registry.partner_fee_receiver_account_id, &account_balance);
         stake_pool.fungible_token.accounts_quantity = 2;
 
+        VersionedStakePool::write_state_version();
+
+        stake_pool
+    }
+
+    fn internal_upgrade(&mut self) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_UPGRADE);
+        self.assert_authorized_sensitive_management_only_by_owner();
+
+        let code = match env::input() {
+            Some(code_) => code_,
+            None => {
+                env::panic_str("Contract code is not passed.");
+            }
+        };
+        if code.is_empty() {
+            env::panic_str("Contract code is not passed.");
+        }
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call("migrate".to_string(), Vec::new(), 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_MIGRATE)
+    }
+
+    fn internal_migrate() -> Self {
+        let stake_pool = match VersionedStakePool::read() {
+            VersionedStakePool::V1(stake_pool_v1) => Self::migrate_from_v1(*stake_pool_v1),
+            VersionedStakePool::V2(stake_pool) => *stake_pool
+        };
+
+        VersionedStakePool::write_state_version();
+
         stake_pool
     }
 
+    /// The storage staking of the stored validators was returned to the manager before the payer was stored.
+    /// Investors registered before the investor registry was added are not listed until the repeated 'add_investor'.
+    fn migrate_from_v1(stake_pool_v1: StakePoolV1) -> Self {
+        let manager_id = stake_pool_v1.account_registry.manager_id.clone();
+        let storage_staking_price_per_validator = Self::calculate_storage_staking_price(stake_pool_v1.validating.storage_usage_per_validator);
+
+        Self {
+            account_registry: stake_pool_v1.account_registry.migrate(),
+            fungible_token: stake_pool_v1.fungible_token,
+            fund: stake_pool_v1.fund.migrate(),
+            fee_registry: stake_pool_v1.fee_registry,
+            validating: stake_pool_v1.validating.migrate(manager_id, storage_staking_price_per_validator),
+            current_epoch_height: stake_pool_v1.current_epoch_height,
+            reward: stake_pool_v1.reward,
+            keeper_reward: KeeperReward::new(env::epoch_height()),
+            config: Config::new(),
+            liquidity_pool: LiquidityPool::new(),
+            pause: Pause::new(),
+            role_registry: RoleRegistry::new(),
+            council: Council::new(),
+            investor_registry: InvestorRegistry::new()
+        }
+    }
+
     fn internal_deposit(&mut self, near_amount: Balance) -> PromiseOrValue<()> {
-        Self::assert_gas_is_enough();
-        Self::assert_minimum_deposit();
-        self.assert_epoch_is_synchronized();
+        self.assert_operation_is_not_paused(PausableOperation::Deposit);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_DEPOSIT);
+        self.assert_minimum_deposit();
+        if self.is_exchange_rate_stale() {
+            env::panic_str("Exchange rate is stale.");
+        }
 
         let predecessor_account_id = env::predecessor_account_id();
 
@@ -467,8 +1039,8 @@ impl StakePool {
             }
         };
 
-        let minimum_near_amount = if MINIMUN_DEPOSIT_AMOUNT > storage_staking_price_per_additional_account {
-            MINIMUN_DEPOSIT_AMOUNT - storage_staking_price_per_additional_account
+        let minimum_near_amount = if self.config.minimum_deposit_amount > storage_staking_price_per_additional_account {
+            self.config.minimum_deposit_amount - storage_staking_price_per_additional_account
         } else {
             env::panic_str("Logic error.");
         };
@@ -493,7 +1065,8 @@ impl StakePool {
             env::panic_str("Insufficient near amount.");
         }
 
-        if self.fund.is_distributed_on_validators_in_current_epoch && self.validating.preffered_validator.is_some() {
+        if self.fund.is_distributed_on_validators_in_current_epoch
+            && self.validating.preffered_validator.is_some() {
             match self.validating.preffered_validator {
                 Some(ref preffered_validator_account_id) => {
                     match self.validating.validator_registry.get(preffered_validator_account_id) {
@@ -502,20 +1075,25 @@ impl StakePool {
                                 StakingContractVersion::Core => {
                                     PromiseOrValue::Promise(
                                         classic_validator::ext(preffered_validator_account_id.clone())
+                                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE)
                                             .with_attached_deposit(near_amount)
                                             .deposit_and_stake()
                                             .then(
                                                 Self::ext(env::current_account_id())
+                                                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DEPOSIT_CALLBACK)
                                                     .deposit_callback(
                                                         predecessor_account_id,
                                                         preffered_validator_account_id.clone(),
-                                                        attached_deposit,
-                                                        near_amount,
-                                                        refundable_near_amount,
-                                                        token_amount,
-                                                        remainder_near_amount,
-                                                        self.current_epoch_height,
-                                                        storage_staking_price_per_additional_account
+                                                        DepositDetails {
+                                                            attached_deposit,
+                                                            near_amount,
+                                                            refundable_near_amount,
+                                                            fee_near_amount: 0,
+                                                            token_amount,
+                                                            near_remainder: remainder_near_amount,
+                                                            storage_staking_price_per_additional_accounts: storage_staking_price_per_additional_account
+                                                        },
+                                                        self.current_epoch_height
                                                     )
                                             )
                                     )
@@ -537,7 +1115,7 @@ impl StakePool {
 
             account_balance.token_amount += token_amount;
             account_balance.classic_near_amount += remainder_near_amount;
-            if let None = self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance) {
+            if self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance).is_none() {
                 self.fungible_token.accounts_quantity += 1;
             }
 
@@ -591,8 +1169,9 @@ impl StakePool {
     }
 
     fn internal_deposit_on_validator(&mut self, near_amount: Balance, validator_account_id: AccountId) -> Promise {
-        Self::assert_gas_is_enough();
-        Self::assert_minimum_deposit();
+        self.assert_operation_is_not_paused(PausableOperation::DepositOnValidator);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR);
+        self.assert_minimum_deposit();
         self.assert_epoch_is_synchronized();
 
         let validator = match self.validating.validator_registry.get(&validator_account_id) {
@@ -601,6 +1180,9 @@ impl StakePool {
                 env::panic_str("Validator account is not registered yet.");
             }
         };
+        if validator.is_retiring {
+            env::panic_str("Validator is retiring.");
+        }
 
         let predecessor_account_id = env::predecessor_account_id();
 
@@ -613,18 +1195,35 @@ impl StakePool {
             }
         };
 
+        // The investor registered before the investor registry was added has no limits.
+        let investor_setting = self.investor_registry.investor_setting_registry
+            .get(&predecessor_account_id)
+            .unwrap_or_else(|| InvestorSetting::new(predecessor_account_id.clone(), 0));
+        if !investor_setting.is_validator_allowed(&validator_account_id) {
+            env::panic_str("Validator is not allowed for the investor.");
+        }
+        if let Some(maximum_staked_balance) = investor_setting.maximum_staked_balance {
+            if investor_investment.staked_balance + near_amount > maximum_staked_balance {
+                env::panic_str("Near amount exceeded the maximum staked balance of the investor.");
+            }
+        }
+        let fee_near_amount = match investor_setting.deposit_fee {
+            Some(ref deposit_fee) => deposit_fee.multiply(near_amount),
+            None => 0
+        };
+
         let mut storage_staking_price_per_additional_accounts: Balance = 0;
 
-        if let None = investor_investment.distribution_registry.get(&validator_account_id) {
+        if investor_investment.distribution_registry.get(&validator_account_id).is_none() {
             storage_staking_price_per_additional_accounts += Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
         }
 
-        if let None = self.fungible_token.account_registry.get(&predecessor_account_id) {
+        if self.fungible_token.account_registry.get(&predecessor_account_id).is_none() {
             storage_staking_price_per_additional_accounts += Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
         };
 
-        let minimum_near_amount = if MINIMUN_DEPOSIT_AMOUNT > storage_staking_price_per_additional_accounts {
-            MINIMUN_DEPOSIT_AMOUNT - storage_staking_price_per_additional_accounts
+        let minimum_near_amount = if self.config.minimum_deposit_amount > storage_staking_price_per_additional_accounts {
+            self.config.minimum_deposit_amount - storage_staking_price_per_additional_accounts
         } else {
             env::panic_str("Logic error.");
         };
@@ -637,10 +1236,10 @@ impl StakePool {
         }
         let available_for_staking_near_amount = attached_deposit - storage_staking_price_per_additional_accounts;
 
-        if near_amount > available_for_staking_near_amount {
+        if near_amount + fee_near_amount > available_for_staking_near_amount {
             env::panic_str("Insufficient near deposit.");
         }
-        let refundable_near_amount = available_for_staking_near_amount - near_amount;
+        let refundable_near_amount = available_for_staking_near_amount - near_amount - fee_near_amount;
 
         let (token_amount, remainder_near_amount) = self.convert_near_amount_to_token_amount(near_amount);
         if token_amount == 0 {
@@ -650,19 +1249,24 @@ impl StakePool {
         match validator.staking_contract_version {
             StakingContractVersion::Core => {
                 classic_validator::ext(validator_account_id.clone())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE)
                     .with_attached_deposit(near_amount)
                     .deposit_and_stake()
                     .then(
                         Self::ext(env::current_account_id())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR_CALLBACK)
                             .deposit_on_validator_callback(
                                 predecessor_account_id,
                                 validator_account_id.clone(),
-                                near_amount,
-                                attached_deposit,
-                                refundable_near_amount,
-                                token_amount,
-                                remainder_near_amount,
-                                storage_staking_price_per_additional_accounts
+                                DepositDetails {
+                                    attached_deposit,
+                                    near_amount,
+                                    refundable_near_amount,
+                                    fee_near_amount,
+                                    token_amount,
+                                    near_remainder: remainder_near_amount,
+                                    storage_staking_price_per_additional_accounts
+                                }
                             )
                     )
             }
@@ -670,7 +1274,8 @@ impl StakePool {
     }
 
     fn internal_instant_withdraw(&mut self, mut token_amount: Balance) -> Promise {
-        Self::assert_gas_is_enough();
+        self.assert_operation_is_not_paused(PausableOperation::InstantWithdraw);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
         Self::assert_natural_deposit();
         self.assert_epoch_is_synchronized();
 
@@ -727,6 +1332,18 @@ impl StakePool {
             }
         }
 
+        let liquidity_pool_near_amount = self.convert_token_amount_to_near_amount(token_amount);
+        if liquidity_pool_near_amount > 0 && liquidity_pool_near_amount <= self.fund.classic_liquidity_balance {
+            return self.instant_withdraw_from_liquidity_pool(
+                predecessor_account_id,
+                account_balance,
+                token_amount_log,
+                liquidity_pool_near_amount,
+                token_balance_log,
+                instant_withdraw_fee_self_log
+            );
+        }
+
         let mut near_amount = self.convert_token_amount_to_near_amount(token_amount) + account_balance.classic_near_amount;
 
         if near_amount == 0 {
@@ -768,8 +1385,7 @@ impl StakePool {
         }
 
         let released_storage_staking_price_per_additional_account_log = if account_balance.token_amount > 0
-            || predecessor_account_id == self.account_registry.self_fee_receiver_account_id
-            || predecessor_account_id == self.account_registry.partner_fee_receiver_account_id {
+            || self.is_protected_account(&predecessor_account_id) {
             self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance);
 
             0
@@ -836,10 +1452,10 @@ impl StakePool {
             .transfer(near_amount)
     }
 
-    fn internal_delayed_withdraw(&mut self, token_amount: Balance) -> PromiseOrValue<()> {
-        Self::assert_gas_is_enough();
+    fn internal_delayed_withdraw(&mut self, token_amount: Balance, receiver_id: Option<AccountId>) -> PromiseOrValue<()> {
+        self.assert_operation_is_not_paused(PausableOperation::DelayedWithdraw);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
         Self::assert_natural_deposit();
-        self.assert_epoch_is_synchronized();
 
         if token_amount == 0 {
             env::panic_str("Insufficient token amount.");
@@ -847,6 +1463,14 @@ impl StakePool {
 
         let predecessor_account_id = env::predecessor_account_id();
 
+        let receiver_account_id = match receiver_id {
+            Some(receiver_id_) => receiver_id_,
+            None => predecessor_account_id.clone()
+        };
+        if receiver_account_id == env::current_account_id() {
+            env::panic_str("Receiver account can not be the pool account.");
+        }
+
         let mut account_balance = match self.fungible_token.account_registry.get(&predecessor_account_id) {
             Some(account_balance_) => account_balance_,
             None => {
@@ -870,47 +1494,28 @@ impl StakePool {
 
         self.fund.classic_staked_balance -= near_amount;
 
+        let current_epoch_height = env::epoch_height();
+
         let attached_deposit = env::attached_deposit();
 
         let (
-            mut refundable_near_amount,
-            reserved_storage_staking_price_per_additional_delayed_withdrawal_log,
             delayed_withdrawal_near_amount_log,
-            epoch_quantity_to_take_delayed_withdrawal_log,
-            mut delayed_withdrawal
-        ) = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&predecessor_account_id) {
-            Some(delayed_withdrawal_) => {
-                (
-                    attached_deposit,
-                    0,
-                    delayed_withdrawal_.near_amount,
-                    delayed_withdrawal_.get_epoch_quantity_to_take_delayed_withdrawal(self.current_epoch_height),
-                    delayed_withdrawal_
-                )
-            }
-            None => {
-                let storage_staking_price_per_additional_delayed_withdrawal =
-                    Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal);
-                if attached_deposit < storage_staking_price_per_additional_delayed_withdrawal {
-                    env::panic_str("Insufficient near deposit.");
-                }
+            epoch_quantity_to_take_delayed_withdrawal_log
+        ) = self.get_account_delayed_withdrawal_details(&receiver_account_id, current_epoch_height);
 
-                (
-                    attached_deposit - storage_staking_price_per_additional_delayed_withdrawal,
-                    storage_staking_price_per_additional_delayed_withdrawal,
-                    0,
-                    0,
-                    DelayedWithdrawal {
-                        near_amount: 0,
-                        started_epoch_height: self.current_epoch_height
-                    },
-                )
-            }
-        };
-        delayed_withdrawal.near_amount += near_amount;
-        delayed_withdrawal.started_epoch_height = self.current_epoch_height;
-        self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(&predecessor_account_id, &delayed_withdrawal);
-        self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount += near_amount;
+        let reserved_storage_staking_price_per_additional_delayed_withdrawal_log =
+            self.calculate_storage_staking_price_per_additional_delayed_withdrawal(&receiver_account_id);
+        if attached_deposit < reserved_storage_staking_price_per_additional_delayed_withdrawal_log {
+            env::panic_str("Insufficient near deposit.");
+        }
+        let mut refundable_near_amount = attached_deposit - reserved_storage_staking_price_per_additional_delayed_withdrawal_log;
+
+        let account_delayed_withdrawal = self.add_delayed_withdrawal(&receiver_account_id, near_amount, current_epoch_height);
+        if self.is_exchange_rate_stale() {
+            self.fund.delayed_withdrawn_fund.queued_to_request_classic_near_amount += near_amount;
+        } else {
+            self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount += near_amount;
+        }
 
         account_balance.token_amount -= token_amount;
         if let Some(investor_investment) = self.validating.investor_investment_registry.get(&predecessor_account_id) {
@@ -919,8 +1524,7 @@ impl StakePool {
             }
         }
         let released_storage_staking_price_per_additional_account_log = if account_balance.token_amount > 0
-            || predecessor_account_id == self.account_registry.self_fee_receiver_account_id
-            || predecessor_account_id == self.account_registry.partner_fee_receiver_account_id  {
+            || self.is_protected_account(&predecessor_account_id) {
             self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance);
 
             0
@@ -943,6 +1547,7 @@ impl StakePool {
             format!(
                 "
                 Delayed withdrawing from @{} in {} epoch.
+                Delayed withdrawal receiver is @{}.
                 Attached deposit is {} yoctoNear.
                 Exchangeable deposit is {} yoctoStNear.
                 Refundable deposit is {} yoctoNear.
@@ -962,7 +1567,8 @@ impl StakePool {
                 New @{} total supply is {} yoctoStNear.
                 ",
                 &current_account_id_log,
-                self.current_epoch_height,
+                current_epoch_height,
+                &receiver_account_id,
                 attached_deposit,
                 token_amount,
                 refundable_near_amount,
@@ -971,8 +1577,8 @@ impl StakePool {
                 delayed_withdrawal_near_amount_log,
                 epoch_quantity_to_take_delayed_withdrawal_log,
                 near_amount,
-                delayed_withdrawal.near_amount,
-                delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(self.current_epoch_height),
+                account_delayed_withdrawal.near_amount,
+                self.config.epoch_quantity_for_delayed_withdrawal,
                 &current_account_id_log,
                 self.fungible_token.total_supply + token_amount,
                 &current_account_id_log,
@@ -1001,7 +1607,8 @@ impl StakePool {
     }
 
     fn internal_delayed_withdraw_from_validator(&mut self, near_amount: Balance, validator_account_id: AccountId) -> PromiseOrValue<()> {
-        Self::assert_gas_is_enough();
+        self.assert_operation_is_not_paused(PausableOperation::DelayedWithdraw);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
         Self::assert_natural_deposit();
         self.assert_epoch_is_synchronized();
 
@@ -1086,39 +1693,19 @@ impl StakePool {
 
         let (
             delayed_withdrawal_near_amount_log,
-            epoch_quantity_to_take_delayed_withdrawal_log,
-            mut delayed_withdrawal
-         ) = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&predecessor_account_id) {
-            Some(delayed_withdrawal_) => {
-                (
-                    delayed_withdrawal_.near_amount,
-                    delayed_withdrawal_.get_epoch_quantity_to_take_delayed_withdrawal(self.current_epoch_height),
-                    delayed_withdrawal_
-                )
-            }
-            None => {
-                let storage_staking_price_per_additional_delayed_withdrawal =
-                    Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal);
-                if refundable_near_amount < storage_staking_price_per_additional_delayed_withdrawal {
-                    env::panic_str("Insufficient near deposit.");
-                }
-                refundable_near_amount -= storage_staking_price_per_additional_delayed_withdrawal;
+            epoch_quantity_to_take_delayed_withdrawal_log
+        ) = self.get_account_delayed_withdrawal_details(&predecessor_account_id, self.current_epoch_height);
 
-                reserved_storage_staking_price_per_additional_accounts_log += storage_staking_price_per_additional_delayed_withdrawal;
+        let storage_staking_price_per_additional_delayed_withdrawal =
+            self.calculate_storage_staking_price_per_additional_delayed_withdrawal(&predecessor_account_id);
+        if refundable_near_amount < storage_staking_price_per_additional_delayed_withdrawal {
+            env::panic_str("Insufficient near deposit.");
+        }
+        refundable_near_amount -= storage_staking_price_per_additional_delayed_withdrawal;
 
-                (
-                    0,
-                    0,
-                    DelayedWithdrawal {
-                        near_amount: 0,
-                        started_epoch_height: self.current_epoch_height
-                    }
-                )
-            }
-        };
-        delayed_withdrawal.started_epoch_height = self.current_epoch_height;
-        delayed_withdrawal.near_amount += near_amount;
-        self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(&predecessor_account_id, &delayed_withdrawal);
+        reserved_storage_staking_price_per_additional_accounts_log += storage_staking_price_per_additional_delayed_withdrawal;
+
+        let account_delayed_withdrawal = self.add_delayed_withdrawal(&predecessor_account_id, near_amount, self.current_epoch_height);
 
         investment_withdrawal.near_amount += near_amount;
         self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.insert(&validator_account_id, &investment_withdrawal);
@@ -1146,8 +1733,7 @@ impl StakePool {
 
         account_balance.token_amount -= token_amount;
         if account_balance.token_amount > 0
-            || predecessor_account_id == self.account_registry.self_fee_receiver_account_id
-            || predecessor_account_id == self.account_registry.partner_fee_receiver_account_id  {
+            || self.is_protected_account(&predecessor_account_id) {
             self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance);
         } else {
             self.fungible_token.account_registry.remove(&predecessor_account_id);
@@ -1197,8 +1783,8 @@ impl StakePool {
                 delayed_withdrawal_near_amount_log,
                 epoch_quantity_to_take_delayed_withdrawal_log,
                 near_amount,
-                delayed_withdrawal.near_amount,
-                delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(self.current_epoch_height),
+                account_delayed_withdrawal.near_amount,
+                self.config.epoch_quantity_for_delayed_withdrawal,
                 &current_account_id_log,
                 self.fungible_token.total_supply + token_amount,
                 &current_account_id_log,
@@ -1226,1051 +1812,4299 @@ impl StakePool {
         PromiseOrValue::Value(())
     }
 
-    fn internal_take_delayed_withdrawal(&mut self) -> Promise {
-        Self::assert_gas_is_enough();
+    fn internal_leave_investment(&mut self, validator_account_ids: Vec<AccountId>) -> PromiseOrValue<()> {
+        self.assert_operation_is_not_paused(PausableOperation::DelayedWithdraw);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
         Self::assert_natural_deposit();
         self.assert_epoch_is_synchronized();
 
         let predecessor_account_id = env::predecessor_account_id();
 
-        let delayed_withdrawal = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.remove(&predecessor_account_id) {
-            Some(delayed_withdrawal_) => delayed_withdrawal_,
+        let mut investor_investment = match self.validating.investor_investment_registry.get(&predecessor_account_id) {
+            Some(investor_investment_) => investor_investment_,
             None => {
-                env::panic_str("Delayed withdrawal account is not registered.");
+                env::panic_str("Investor account is not registered yet.");
             }
         };
-        if !delayed_withdrawal.can_take_delayed_withdrawal(self.current_epoch_height) {
-            env::panic_str("Wrong epoch for withdrawal.");
+        if self.validating.investment_move_registry.get(&predecessor_account_id).is_some() {
+            env::panic_str("Investment move is not completed yet.");
         }
 
-        self.fund.delayed_withdrawn_fund.balance -= delayed_withdrawal.near_amount;
+        let attached_deposit = env::attached_deposit();
 
-        let near_amount = delayed_withdrawal.near_amount
-            + Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal)
-            + env::attached_deposit();
+        let mut reserved_storage_staking_price: Balance = 0;
 
-        Promise::new(predecessor_account_id)
-            .transfer(near_amount)
-    }
+        let mut released_storage_staking_price = self.remove_empty_distributions(&mut investor_investment, &validator_account_ids);
 
-    fn internal_increase_validator_stake(&mut self, validator_account_id: AccountId, near_amount: Balance) -> Promise {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
+        let distribution_registry: Vec<(AccountId, Balance)> = validator_account_ids
+            .into_iter()
+            .filter_map(
+                |validator_account_id| investor_investment.distribution_registry
+                    .get(&validator_account_id)
+                    .map(|staked_balance| (validator_account_id, staked_balance))
+            )
+            .collect();
 
-        if near_amount == 0 {
-            env::panic_str("Insufficient near amount.");
+        let mut near_amount: Balance = 0;
+
+        for (validator_account_id, staked_balance) in distribution_registry.into_iter() {
+            let validator = match self.validating.validator_registry.get(&validator_account_id) {
+                Some(validator_) => validator_,
+                None => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            };
+
+            let mut investment_withdrawal = match self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
+                Some(investment_withdrawal_) => investment_withdrawal_,
+                None => {
+                    reserved_storage_staking_price +=
+                        Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal);
+
+                    InvestmentWithdrawal {
+                        near_amount: 0,
+                        account_id: predecessor_account_id.clone()
+                    }
+                }
+            };
+            if staked_balance > (validator.balance.investment_near_amount - investment_withdrawal.near_amount) {
+                env::panic_str("Near amount exceeded the available near balance on validator.");
+            }
+
+            investment_withdrawal.near_amount += staked_balance;
+            self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.insert(&validator_account_id, &investment_withdrawal);
+
+            investor_investment.distribution_registry.remove(&validator_account_id);
+            investor_investment.distributions_quantity -= 1;
+
+            released_storage_staking_price += Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
+
+            near_amount += staked_balance;
         }
-        if near_amount > self.fund.classic_unstaked_balance {
-            env::panic_str("Near amount exceeded the available unstaked near balance.");
+        if investor_investment.distributions_quantity > 0 || investor_investment.staked_balance != near_amount {
+            env::panic_str("Not all validators with the investor distributions are listed.");
         }
 
-        let validator = match self.validating.validator_registry.get(&validator_account_id) {
-            Some(validator_) => validator_,
-            None => {
-                env::panic_str("Validator account is not registered yet.");
+        let mut token_amount: Balance = 0;
+
+        if near_amount > 0 {
+            if near_amount > self.fund.investment_staked_balance {
+                env::panic_str("Token amount exceeded the available staked near balance.");
             }
-        };
-        if validator.is_only_for_investment {
-            env::panic_str("Validator is used only for investment purpose.");
-        }
 
-        match validator.staking_contract_version {
-            StakingContractVersion::Core => {
-                classic_validator::ext(validator_account_id.clone())
-                    .with_attached_deposit(near_amount)
-                    .deposit_and_stake()
-                    .then(
-                        Self::ext(env::current_account_id())
-                            .increase_validator_stake_callback(validator_account_id, near_amount, env::epoch_height())
-                    )
+            token_amount = self.convert_near_amount_to_token_amount(near_amount).0;
+
+            let mut account_balance = match self.fungible_token.account_registry.get(&predecessor_account_id) {
+                Some(account_balance_) => account_balance_,
+                None => {
+                    env::panic_str("Token account is not registered.");
+                }
+            };
+            if account_balance.token_amount < token_amount {
+                env::panic_str("Token amount exceeded the available token balance.");
+            }
+
+            reserved_storage_staking_price += self.calculate_storage_staking_price_per_additional_delayed_withdrawal(&predecessor_account_id);
+
+            self.add_delayed_withdrawal(&predecessor_account_id, near_amount, self.current_epoch_height);
+
+            self.fund.investment_staked_balance -= near_amount;
+            self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount += near_amount;
+
+            account_balance.token_amount -= token_amount;
+            if account_balance.token_amount > 0
+                || self.is_protected_account(&predecessor_account_id) {
+                self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance);
+            } else {
+                self.fungible_token.account_registry.remove(&predecessor_account_id);
+                self.fungible_token.accounts_quantity -= 1;
+
+                released_storage_staking_price += Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
             }
+
+            self.fungible_token.total_supply -= token_amount;
+        }
+
+        if attached_deposit < reserved_storage_staking_price {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        // The investor is deregistered at once, because the unstake is settled without the investor registration:
+        // the stake is requested from validators by the investment withdrawals, which store the account for their
+        // storage staking refund, and the investor gets the Near amount with the delayed withdrawal.
+        self.validating.investor_investment_registry.remove(&predecessor_account_id);
+
+        self.refund_investor_registration(&predecessor_account_id, self.account_registry.manager_id.clone());
+
+        let refundable_near_amount = attached_deposit - reserved_storage_staking_price + released_storage_staking_price;
+
+        env::log_str(
+            format!(
+                "
+                @{} left investment in {} epoch.
+                Delayed withdrawn {} yoctoNear for {} yoctoStNear.
+                Reserved storage staking price is {} yoctoNear.
+                Released storage staking price is {} yoctoNear.
+                Refundable deposit is {} yoctoNear.
+                ",
+                &predecessor_account_id,
+                self.current_epoch_height,
+                near_amount,
+                token_amount,
+                reserved_storage_staking_price,
+                released_storage_staking_price,
+                refundable_near_amount
+            ).as_str()
+        );
+
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            );
         }
+
+        PromiseOrValue::Value(())
     }
 
-    fn internal_requested_decrease_validator_stake(
+    fn internal_move_investment(
         &mut self,
-        validator_account_id: AccountId,
-        near_amount: Balance,
-        stake_decreasing_type: StakeDecreasingType
-    ) -> Promise {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_desynchronized();
-        self.assert_authorized_management_only_by_manager();
-        if !Self::is_right_epoch(env::epoch_height()) {
-            env::panic_str("Epoch is not intended for a requested decrease validator stake request.");
-        }
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: Balance
+    ) -> PromiseOrValue<()> {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_epoch_is_synchronized();
 
         if near_amount == 0 {
             env::panic_str("Insufficient near amount.");
         }
+        if source_validator_account_id == target_validator_account_id {
+            env::panic_str("Source and target validators must be different.");
+        }
 
-        let validator = match self.validating.validator_registry.get(&validator_account_id) {
+        let mut source_validator = match self.validating.validator_registry.get(&source_validator_account_id) {
             Some(validator_) => validator_,
             None => {
                 env::panic_str("Validator account is not registered yet.");
             }
         };
-        match stake_decreasing_type {
-            StakeDecreasingType::Classic => {
-                if near_amount > validator.balance.classic_near_amount {
-                    env::panic_str("Near amount exceeded the available staked near balance.");
-                }
-                if near_amount > self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount {
-                    env::panic_str("Near amount is more than requested near amount.");
-                }
-            }
-            StakeDecreasingType::Investment => {
-                if near_amount > validator.balance.investment_near_amount {
-                    env::panic_str("Near amount exceeded the available unstaked near balance.");
-                }
-                if near_amount > self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount {
-                    env::panic_str("Near amount is more than requested near amount.");
-                }
 
-                let investment_withdrawal = match self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
-                    Some(investment_withdrawal_) => investment_withdrawal_,
-                    None => {
-                        env::panic_str("Investment withdrawal account is not registered yet.");
-                    }
-                };
-                if near_amount > investment_withdrawal.near_amount {
-                    env::panic_str("Near amount is more than requested near amount from validator.");
+        match self.validating.validator_registry.get(&target_validator_account_id) {
+            Some(target_validator) => {
+                if target_validator.is_retiring {
+                    env::panic_str("Validator is retiring.");
                 }
             }
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
         }
 
-        let current_account_id = env::current_account_id();
+        let predecessor_account_id = env::predecessor_account_id();
 
-        match validator.staking_contract_version {
-            StakingContractVersion::Core => {
-                classic_validator::ext(validator_account_id.clone())
-                    .get_account_unstaked_balance(current_account_id.clone())
-                    .then(
-                        Self::ext(current_account_id)
-                            .requested_decrease_validator_stake_callback_1(
-                                validator_account_id,
-                                near_amount,
-                                stake_decreasing_type,
-                                Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal)
-                            )
-                    )
+        let mut investor_investment = match self.validating.investor_investment_registry.get(&predecessor_account_id) {
+            Some(investor_investment_) => investor_investment_,
+            None => {
+                env::panic_str("Investor account is not registered yet.");
             }
+        };
+        if self.validating.investment_move_registry.get(&predecessor_account_id).is_some() {
+            env::panic_str("Investment move is already requested.");
         }
-    }
-
-    fn internal_take_unstaked_balance(&mut self, validator_account_id: AccountId) -> Promise {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_desynchronized();
-        self.assert_authorized_management_only_by_manager();
-
-        let current_epoch_height = env::epoch_height();
-
-        if !Self::is_right_epoch(current_epoch_height) {
-            env::panic_str("Epoch is not intended for a take unstaked balance.");
+        if let Some(investor_setting) = self.investor_registry.investor_setting_registry.get(&predecessor_account_id) {
+            if !investor_setting.is_validator_allowed(&target_validator_account_id) {
+                env::panic_str("Validator is not allowed for the investor.");
+            }
         }
-        match self.validating.validator_registry.get(&validator_account_id) {
-            Some(validator) => {
-                if validator.balance.requested_to_withdrawal_near_amount == 0 {
-                    env::panic_str("Insufficient unstaked balance on validator.");
-                }
-                if validator.last_update_epoch_height >= current_epoch_height {
-                    env::panic_str("Validator is already updated.");
-                }
 
-                match validator.staking_contract_version {
-                    StakingContractVersion::Core => {
-                        classic_validator::ext(validator_account_id.clone())
-                            .withdraw(validator.balance.requested_to_withdrawal_near_amount.into())
-                            .then(
-                                Self::ext(env::current_account_id())
-                                    .take_unstaked_balance_callback(
-                                        validator_account_id,
-                                        validator.balance.requested_to_withdrawal_near_amount
-                                    )
-                            )
-                    }
-                }
-            }
+        let mut staked_balance = match investor_investment.distribution_registry.get(&source_validator_account_id) {
+            Some(staked_balance_) => staked_balance_,
             None => {
-                env::panic_str("Validator account is not registered yet.");
+                env::panic_str("There is no investor stake on this validator.");
             }
+        };
+        if near_amount > staked_balance {
+            env::panic_str("Near amount exceeded the available investor near balance on validator.");
         }
-    }
 
-    fn internal_update_validator(&mut self, validator_account_id: AccountId) -> Promise {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_desynchronized();
-        self.assert_authorized_management_only_by_manager();
+        let attached_deposit = env::attached_deposit();
 
-        match self.validating.validator_registry.get(&validator_account_id) {
-            Some(validator) => {
-                let current_epoch_height = env::epoch_height();
+        let storage_staking_price_per_additional_distribution =
+            Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
 
-                if validator.last_update_epoch_height < current_epoch_height {
-                    let current_account_id = env::current_account_id();
-                    match validator.staking_contract_version {
-                        StakingContractVersion::Core => {
-                            classic_validator::ext(validator_account_id.clone())
-                                .get_account_total_balance(current_account_id.clone())
-                                .then(
-                                    Self::ext(current_account_id)
-                                        .update_validator_callback(validator_account_id, current_epoch_height)
-                                )
-                        }
-                    }
-                } else {
-                    env::panic_str("Validator is already updated.");
-                }
-            }
+        let mut storage_staking_price_per_additional_accounts =
+            Self::calculate_storage_staking_price(self.validating.storage_usage_per_investment_move)
+            + storage_staking_price_per_additional_distribution;
+
+        let mut investment_withdrawal = match self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&source_validator_account_id) {
+            Some(investment_withdrawal_) => investment_withdrawal_,
             None => {
-                env::panic_str("Validator account is not registered yet.");
+                storage_staking_price_per_additional_accounts +=
+                    Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal);
+
+                InvestmentWithdrawal {
+                    near_amount: 0,
+                    account_id: predecessor_account_id.clone()
+                }
             }
+        };
+        if near_amount > (source_validator.balance.investment_near_amount - investment_withdrawal.near_amount) {
+            env::panic_str("Near amount exceeded the available near balance on validator.");
         }
-    }
 
-    fn internal_update(&mut self) {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_desynchronized();
-        self.assert_authorized_management_only_by_manager();
+        if attached_deposit < storage_staking_price_per_additional_accounts {
+            env::panic_str("Insufficient near deposit.");
+        }
+        let mut refundable_near_amount = attached_deposit - storage_staking_price_per_additional_accounts;
 
-        let current_epoch_height = env::epoch_height();
+        investment_withdrawal.near_amount += near_amount;
+        self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.insert(&source_validator_account_id, &investment_withdrawal);
+        self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount += near_amount;
 
-        let common_balance_log = self.fund.get_common_balance();
+        source_validator.balance.needed_to_request_moving_near_amount += near_amount;
+        self.validating.validator_registry.insert(&source_validator_account_id, &source_validator);
 
-        let total_supply_log = self.fungible_token.total_supply;
+        if near_amount < staked_balance {
+            staked_balance -= near_amount;
 
-        if self.validating.validators_quantity > 0 {
-            if (self.validating.quantity_of_validators_updated_in_current_epoch / self.validating.validators_quantity == 0)
-                || (self.validating.quantity_of_validators_updated_in_current_epoch % self.validating.validators_quantity != 0) {
-                env::panic_str("Some validators are not updated.");
-            }
+            investor_investment.distribution_registry.insert(&source_validator_account_id, &staked_balance);
+        } else {
+            investor_investment.distribution_registry.remove(&source_validator_account_id);
+            investor_investment.distributions_quantity -= 1;
+
+            refundable_near_amount += storage_staking_price_per_additional_distribution;
+        }
+        self.validating.investor_investment_registry.insert(&predecessor_account_id, &investor_investment);
 
-            if Self::is_right_epoch(current_epoch_height)
-                && (self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount > 0
-                    || self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount > 0) {
-                    env::panic_str("Some funds are not unstaked from validators.");
+        self.validating.investment_move_registry.insert(
+            &predecessor_account_id,
+            &InvestmentMove {
+                source_validator_account_id: source_validator_account_id.clone(),
+                target_validator_account_id: target_validator_account_id.clone(),
+                near_amount,
+                started_epoch_height: self.current_epoch_height
             }
+        );
+        self.validating.moving_investment_near_amount += near_amount;
 
-            self.fund.classic_staked_balance += self.reward.previous_epoch_rewards_from_validators_near_amount;
-            self.validating.quantity_of_validators_updated_in_current_epoch = 0;
-            self.reward.total_rewards_from_validators_near_amount += self.reward.previous_epoch_rewards_from_validators_near_amount;
+        env::log_str(
+            format!(
+                "
+                Requested investment move of @{} from validator @{} to validator @{} in {} epoch.
+                Attached deposit is {} yoctoNear.
+                Moved Near amount is {} yoctoNear.
+                Reserved storage staking price is {} yoctoNear.
+                Refundable deposit is {} yoctoNear.
+                ",
+                &predecessor_account_id,
+                &source_validator_account_id,
+                &target_validator_account_id,
+                self.current_epoch_height,
+                attached_deposit,
+                near_amount,
+                storage_staking_price_per_additional_accounts,
+                refundable_near_amount
+            ).as_str()
+        );
 
-            let (previous_epoch_rewards_from_validators_token_amount, _) = self.convert_near_amount_to_token_amount(
-                self.reward.previous_epoch_rewards_from_validators_near_amount
-            );
-
-            let mut reward_fee_self_log: Option<Fee> = None;
-
-            if let Some(ref reward_fee) = self.fee_registry.reward_fee {
-                reward_fee_self_log = Some(reward_fee.self_fee.clone());
-
-                let mut reward_fee_self_token_amount = reward_fee.self_fee.multiply(previous_epoch_rewards_from_validators_token_amount);
-                if reward_fee_self_token_amount != 0 {
-                    self.fungible_token.total_supply += reward_fee_self_token_amount;
-
-                    if let Some(ref reward_fee_partner) = reward_fee.partner_fee {
-                        let reward_fee_partner_token_amount = reward_fee_partner.multiply(reward_fee_self_token_amount);
-                        if reward_fee_partner_token_amount != 0 {
-                            reward_fee_self_token_amount -= reward_fee_partner_token_amount;
-
-                            let mut account_balance = match self.fungible_token.account_registry.get(&self.account_registry.partner_fee_receiver_account_id) {
-                                Some(account_balance_) => account_balance_,
-                                None => {
-                                    env::panic_str("Nonexecutable code. Object must exist.");
-                                }
-                            };
-                            account_balance.token_amount += reward_fee_partner_token_amount;
-
-                            self.fungible_token.account_registry.insert(&self.account_registry.partner_fee_receiver_account_id, &account_balance);
-                        }
-                    }
-
-                    let mut account_balance = match self.fungible_token.account_registry.get(&self.account_registry.self_fee_receiver_account_id) {
-                        Some(account_balance_) => account_balance_,
-                        None => {
-                            env::panic_str("Nonexecutable code. Object must exist.");
-                        }
-                    };
-                    account_balance.token_amount += reward_fee_self_token_amount;
-
-                    self.fungible_token.account_registry.insert(&self.account_registry.self_fee_receiver_account_id, &account_balance);
-                }
-            }
-
-            let current_account_id_log = env::current_account_id();
-            env::log_str(
-                format!(
-                    "
-                    Updating @{} pool from {} epoch to {} epoch.
-                    Old @{} total supply is {} yoctoStNear.
-                    Old @{} balance is {} yoctoNear.
-                    Received rewards from validators is {} yoctoNear.
-                    Fee is {:?}.
-                    Received token amount as fee is {}.
-                    New @{} balance is {} yoctoNear.
-                    New @{} total supply is {} yoctoStNear.
-                    ",
-                    current_account_id_log,
-                    current_epoch_height - 1,
-                    current_epoch_height,
-                    current_account_id_log,
-                    total_supply_log,
-                    current_account_id_log,
-                    common_balance_log,
-                    self.reward.previous_epoch_rewards_from_validators_near_amount,
-                    reward_fee_self_log,
-                    self.fungible_token.total_supply - total_supply_log,
-                    current_account_id_log,
-                    self.fund.get_common_balance(),
-                    current_account_id_log,
-                    self.fungible_token.total_supply
-                ).as_str()
-            );
-
-            self.reward.previous_epoch_rewards_from_validators_near_amount = 0;
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            )
         }
 
-        self.fund.is_distributed_on_validators_in_current_epoch = false;
-        self.current_epoch_height = current_epoch_height;
+        PromiseOrValue::Value(())
     }
 
-    fn internal_add_validator(
-        &mut self,
-        validator_account_id: AccountId,
-        staking_contract_version: StakingContractVersion,
-        is_only_for_investment: bool,
-        is_preferred: bool
-    ) -> PromiseOrValue<()> {
-        Self::assert_gas_is_enough();
-        Self::assert_natural_deposit();
+    fn internal_complete_investment_move(&mut self, investor_account_id: AccountId) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE);
         self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
-
-        if is_preferred && is_only_for_investment {
-            env::panic_str("Prefferred validator can not be only for investment.");
-        }
-
-        let attached_deposit = env::attached_deposit();
 
-        let storage_staking_price_per_additional_validator = Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator);
-        if attached_deposit < storage_staking_price_per_additional_validator {
-            env::panic_str("Insufficient near deposit.");
-        }
+        let predecessor_account_id = env::predecessor_account_id();
 
-        if let Some(_) = self.validating.validator_registry.insert(
-            &validator_account_id, &Validator::new(staking_contract_version, is_only_for_investment)
-        ) {
-            env::panic_str("Validator account is already registered.");
+        if predecessor_account_id != investor_account_id
+            && predecessor_account_id != self.account_registry.manager_id
+            && !self.role_registry.get(Role::Operator).contains(&predecessor_account_id) {
+            env::panic_str("Unauthorized management. Management must be carried out either by the investor, operator or manager of the pool.");
         }
-        self.validating.validators_quantity += 1;
 
-        if is_preferred {
-            self.validating.preffered_validator = Some(validator_account_id);
+        let investment_move = match self.validating.investment_move_registry.remove(&investor_account_id) {
+            Some(investment_move_) => investment_move_,
+            None => {
+                env::panic_str("Investment move is not registered.");
+            }
+        };
+        if !investment_move.can_complete_investment_move(self.current_epoch_height, &self.config) {
+            env::panic_str("Wrong epoch for investment move completion.");
         }
-
-        let near_amount = attached_deposit - storage_staking_price_per_additional_validator;
-        if near_amount > 0 {
-            return PromiseOrValue::Promise(
-                Promise::new(env::predecessor_account_id())
-                    .transfer(near_amount)
-            );
+        let withdrawn_near_amount = self.validating.withdrawn_moving_investment_registry
+            .get(&investment_move.source_validator_account_id)
+            .unwrap_or(0);
+        if investment_move.near_amount > withdrawn_near_amount {
+            env::panic_str("Moved Near amount is not taken from validator yet.");
         }
 
-        PromiseOrValue::Value(())
-    }
-
-    fn internal_remove_validator(&mut self, validator_account_id: AccountId) -> Promise {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
-
-        let validator = match self.validating.validator_registry.remove(&validator_account_id) {
+        let target_validator = match self.validating.validator_registry.get(&investment_move.target_validator_account_id) {
             Some(validator_) => validator_,
             None => {
                 env::panic_str("Validator account is not registered yet.");
             }
         };
-        if validator.balance.classic_near_amount > 0
-            || validator.balance.investment_near_amount > 0
-            || validator.balance.requested_to_withdrawal_near_amount > 0 {
-            env::panic_str("Validator has an available balance.");
+        if target_validator.is_retiring {
+            env::panic_str("Validator is retiring.");
         }
 
-        self.validating.validators_quantity -= 1;
+        if investment_move.near_amount < withdrawn_near_amount {
+            self.validating.withdrawn_moving_investment_registry.insert(
+                &investment_move.source_validator_account_id, &(withdrawn_near_amount - investment_move.near_amount)
+            );
+        } else {
+            self.validating.withdrawn_moving_investment_registry.remove(&investment_move.source_validator_account_id);
+        }
+        self.validating.withdrawn_moving_investment_near_amount -= investment_move.near_amount;
 
-        if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
-            if *preffered_validator_account_id == validator_account_id {
-                self.validating.preffered_validator = None;
+        match target_validator.staking_contract_version {
+            StakingContractVersion::Core => {
+                classic_validator::ext(investment_move.target_validator_account_id.clone())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE)
+                    .with_attached_deposit(investment_move.near_amount)
+                    .deposit_and_stake()
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE_CALLBACK)
+                            .complete_investment_move_callback(
+                                investor_account_id,
+                                investment_move.source_validator_account_id,
+                                investment_move.target_validator_account_id,
+                                investment_move.near_amount,
+                                investment_move.started_epoch_height
+                            )
+                    )
             }
         }
-
-        let refundable_near_amount = Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator);
-
-        Promise::new(env::predecessor_account_id())
-            .transfer(refundable_near_amount)
     }
 
-    fn internal_change_validator_investment_context(&mut self, validator_account_id: AccountId, is_only_for_investment: bool) {
-        Self::assert_gas_is_enough();
+    fn internal_take_delayed_withdrawal(&mut self, receiver_id: Option<AccountId>) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::TakeDelayedWithdrawal);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL);
+        Self::assert_natural_deposit();
         self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
 
-        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
-            Some(validator_) => validator_,
-            None => {
-                env::panic_str("Validator account is not registered yet.");
-            }
+        let predecessor_account_id = env::predecessor_account_id();
+
+        let receiver_account_id = match receiver_id {
+            Some(receiver_id_) => receiver_id_,
+            None => predecessor_account_id.clone()
         };
 
-        if validator.is_only_for_investment == is_only_for_investment {
-            env::panic_str("Changing the state to the same state.");
-        }
+        let near_amount = self.take_account_delayed_withdrawal(&predecessor_account_id) + env::attached_deposit();
 
-        if is_only_for_investment {
-            if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
-                if *preffered_validator_account_id == validator_account_id {
-                    env::panic_str("Prefferred validator can not be only for investment.");
-                }
-            }
+        Promise::new(receiver_account_id)
+            .transfer(near_amount)
+    }
 
-            if validator.balance.classic_near_amount > 0 {
-                env::panic_str("Validator classic staked balance is not equal to zero.");
-            }
-        }
+    fn internal_take_delayed_withdrawal_for(&mut self, account_id: AccountId) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::TakeDelayedWithdrawal);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL);
+        self.assert_epoch_is_synchronized();
 
-        validator.is_only_for_investment = is_only_for_investment;
-        self.validating.validator_registry.insert(&validator_account_id, &validator);
+        let near_amount = self.take_account_delayed_withdrawal(&account_id);
+
+        Promise::new(account_id)
+            .transfer(near_amount)
     }
 
-    fn internal_change_preffered_validator(&mut self, validator_account_id: Option<AccountId>) {
-        Self::assert_gas_is_enough();
+    fn internal_add_liquidity(&mut self, near_amount: Balance) -> PromiseOrValue<()> {
+        self.assert_operation_is_not_paused(PausableOperation::LiquidityPool);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_minimum_deposit();
         self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
 
-        match validator_account_id {
-            Some(validator_account_id_) => {
-                let validator = match self.validating.validator_registry.get(&validator_account_id_) {
-                    Some(validator_) => validator_,
-                    None => {
-                        env::panic_str("Validator account is not registered yet.");
-                    }
-                };
+        if near_amount == 0 {
+            env::panic_str("Insufficient near amount.");
+        }
 
-                if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
-                    if *preffered_validator_account_id == validator_account_id_ {
-                        env::panic_str("Changing the state to the same state.");
-                    }
-                }
+        let predecessor_account_id = env::predecessor_account_id();
 
-                if validator.is_only_for_investment {
-                    env::panic_str("Prefferred validator can not be only for investment.");
-                }
+        let attached_deposit = env::attached_deposit();
 
-                self.validating.preffered_validator = Some(validator_account_id_);
-            }
-            None => {
-                if let None = self.validating.preffered_validator {
-                    env::panic_str("Changing the state to the same state.");
-                }
+        let (storage_staking_price_per_additional_share, share_amount) = match self.liquidity_pool.share_registry.get(&predecessor_account_id) {
+            Some(share_amount_) => (0, share_amount_),
+            None => (Self::calculate_storage_staking_price(self.liquidity_pool.storage_usage_per_share), 0)
+        };
 
-                self.validating.preffered_validator = None;
-            }
+        if attached_deposit < storage_staking_price_per_additional_share {
+            env::panic_str("Insufficient near deposit.");
         }
-    }
-
-    fn internal_add_investor(&mut self, investor_account_id: AccountId) -> PromiseOrValue<()> {
-        Self::assert_gas_is_enough();
-        Self::assert_natural_deposit();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
+        let available_for_providing_near_amount = attached_deposit - storage_staking_price_per_additional_share;
 
-        let storage_staking_price_per_additional_investor_investment = Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment);
-        if env::attached_deposit() < storage_staking_price_per_additional_investor_investment {
+        if near_amount > available_for_providing_near_amount {
             env::panic_str("Insufficient near deposit.");
         }
+        let refundable_near_amount = available_for_providing_near_amount - near_amount;
 
-        if let Some(_) = self.validating.investor_investment_registry.insert(
-            &investor_account_id, &InvestorInvestment::new(investor_account_id.clone())
-        ) {
-            env::panic_str("Investor account is already registered.");
+        let liquidity_pool_common_near_amount = self.get_liquidity_pool_common_near_amount();
+
+        let additional_share_amount = if self.liquidity_pool.total_share_amount == 0 || liquidity_pool_common_near_amount == 0 {
+            near_amount
+        } else {
+            (
+                U256::from(near_amount)
+                * U256::from(self.liquidity_pool.total_share_amount)
+                / U256::from(liquidity_pool_common_near_amount)
+            ).as_u128()
+        };
+        if additional_share_amount == 0 {
+            env::panic_str("Insufficient near amount.");
         }
 
-        let near_amount = env::attached_deposit() - storage_staking_price_per_additional_investor_investment;
-        if near_amount > 0 {
+        self.fund.classic_liquidity_balance += near_amount;
+
+        self.liquidity_pool.total_share_amount += additional_share_amount;
+        if self.liquidity_pool.share_registry.insert(&predecessor_account_id, &(share_amount + additional_share_amount)).is_none() {
+            self.liquidity_pool.liquidity_providers_quantity += 1;
+        }
+
+        let current_account_id_log = env::current_account_id();
+        env::log_str(
+            format!(
+                "
+                Liquidity added to @{} in {} epoch.
+                Attached deposit is {} yoctoNear.
+                Provided deposit is {} yoctoNear.
+                Reserved storage staking price is {} yoctoNear.
+                Refundable deposit is {} yoctoNear.
+                Old liquidity pool balance is {} yoctoNear.
+                @{} received {} shares.
+                New @{} share amount is {}.
+                New liquidity pool balance is {} yoctoNear.
+                New liquidity pool total share amount is {}.
+                ",
+                &current_account_id_log,
+                self.current_epoch_height,
+                attached_deposit,
+                near_amount,
+                storage_staking_price_per_additional_share,
+                refundable_near_amount,
+                liquidity_pool_common_near_amount,
+                &predecessor_account_id,
+                additional_share_amount,
+                &predecessor_account_id,
+                share_amount + additional_share_amount,
+                liquidity_pool_common_near_amount + near_amount,
+                self.liquidity_pool.total_share_amount
+            ).as_str()
+        );
+
+        if refundable_near_amount > 0 {
             return PromiseOrValue::Promise(
-                Promise::new(env::predecessor_account_id())
-                    .transfer(near_amount)
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
             );
         }
 
         PromiseOrValue::Value(())
     }
 
-    fn internal_remove_investor(&mut self, investor_account_id: AccountId) -> Promise {
-        Self::assert_gas_is_enough();
+    fn internal_remove_liquidity(&mut self, share_amount: Balance) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::LiquidityPool);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
         self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
 
-        let investor_investment = match self.validating.investor_investment_registry.remove(&investor_account_id) {
-            Some(investor_investment_) => investor_investment_,
+        if share_amount == 0 {
+            env::panic_str("Insufficient share amount.");
+        }
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        let available_share_amount = match self.liquidity_pool.share_registry.get(&predecessor_account_id) {
+            Some(available_share_amount_) => available_share_amount_,
             None => {
-                env::panic_str("Investor account is not registered yet.");
+                env::panic_str("Liquidity provider account is not registered.");
             }
         };
-        if investor_investment.staked_balance > 0 || investor_investment.distributions_quantity > 0 {
-            env::panic_str("Validator has an available balance.");
+        if share_amount > available_share_amount {
+            env::panic_str("Share amount exceeded the available share amount.");
         }
 
-        let near_amount = Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment);
+        let liquidity_pool_common_near_amount = self.get_liquidity_pool_common_near_amount();
 
-        Promise::new(env::predecessor_account_id())
-            .transfer(near_amount)
-    }
+        let share_near_amount = (
+            U256::from(share_amount)
+            * U256::from(liquidity_pool_common_near_amount)
+            / U256::from(self.liquidity_pool.total_share_amount)
+        ).as_u128();
 
-    fn internal_change_manager(&mut self, manager_id: AccountId) {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management();
+        let mut near_amount = share_near_amount.min(self.fund.classic_liquidity_balance);
 
-        self.account_registry.manager_id = manager_id;
-    }
+        let (token_amount, _) = self.convert_near_amount_to_token_amount(share_near_amount - near_amount);
+        if token_amount > self.liquidity_pool.token_amount {
+            env::panic_str("Share amount exceeded the available liquidity. The rest of the liquidity pool is in the delayed withdrawals.");
+        }
+        if near_amount == 0 && token_amount == 0 {
+            env::panic_str("Insufficient share amount.");
+        }
 
-    fn internal_change_reward_fee(&mut self, reward_fee_self: Option<Fee>, reward_fee_partner: Option<Fee>) {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
+        let mut attached_deposit = env::attached_deposit();
 
-        if reward_fee_self.is_none() && reward_fee_partner.is_some() {
-            env::panic_str("Reward fees are not valid.");
-        }
-        self.fee_registry.reward_fee = if let Some(reward_fee_self_) = reward_fee_self {
-            reward_fee_self_.assert_valid();
+        let attached_deposit_log = attached_deposit;
+
+        let mut reserved_storage_staking_price_per_additional_account_log: Balance = 0;
+
+        if token_amount > 0 {
+            let account_balance = match self.fungible_token.account_registry.get(&predecessor_account_id) {
+                Some(account_balance_) => account_balance_,
+                None => {
+                    let storage_staking_price_per_additional_account =
+                        Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
+                    if attached_deposit < storage_staking_price_per_additional_account {
+                        env::panic_str("Insufficient near deposit.");
+                    }
+                    attached_deposit -= storage_staking_price_per_additional_account;
+
+                    reserved_storage_staking_price_per_additional_account_log = storage_staking_price_per_additional_account;
+
+                    self.fungible_token.accounts_quantity += 1;
+
+                    AccountBalance { token_amount: 0, classic_near_amount: 0, investment_near_amount: 0 }
+                }
+            };
+
+            self.fungible_token.account_registry.insert(
+                &predecessor_account_id,
+                &AccountBalance {
+                    token_amount: account_balance.token_amount + token_amount,
+                    ..account_balance
+                }
+            );
+
+            self.liquidity_pool.token_amount -= token_amount;
+        }
+
+        self.fund.classic_liquidity_balance -= near_amount;
+
+        let near_amount_log = near_amount;
+
+        self.liquidity_pool.total_share_amount -= share_amount;
+
+        let released_storage_staking_price_per_additional_share_log = if available_share_amount > share_amount {
+            self.liquidity_pool.share_registry.insert(&predecessor_account_id, &(available_share_amount - share_amount));
+
+            0
+        } else {
+            self.liquidity_pool.share_registry.remove(&predecessor_account_id);
+            self.liquidity_pool.liquidity_providers_quantity -= 1;
+
+            let storage_staking_price_per_additional_share = Self::calculate_storage_staking_price(self.liquidity_pool.storage_usage_per_share);
+
+            near_amount += storage_staking_price_per_additional_share;
+
+            storage_staking_price_per_additional_share
+        };
+
+        near_amount += attached_deposit;
+
+        let current_account_id_log = env::current_account_id();
+        env::log_str(
+            format!(
+                "
+                Liquidity removed from @{} in {} epoch.
+                Attached deposit is {} yoctoNear.
+                Exchangeable share amount is {}.
+                Share value is {} yoctoNear.
+                Reserved storage staking price is {} yoctoNear.
+                Released storage staking price is {} yoctoNear.
+                Old liquidity pool balance is {} yoctoNear.
+                @{} received {} yoctoNear.
+                @{} received {} yoctoStNear.
+                New @{} share amount is {}.
+                New liquidity pool balance is {} yoctoNear.
+                New liquidity pool total share amount is {}.
+                ",
+                &current_account_id_log,
+                self.current_epoch_height,
+                attached_deposit_log,
+                share_amount,
+                share_near_amount,
+                reserved_storage_staking_price_per_additional_account_log,
+                released_storage_staking_price_per_additional_share_log,
+                liquidity_pool_common_near_amount,
+                &predecessor_account_id,
+                near_amount_log,
+                &predecessor_account_id,
+                token_amount,
+                &predecessor_account_id,
+                available_share_amount - share_amount,
+                self.get_liquidity_pool_common_near_amount(),
+                self.liquidity_pool.total_share_amount
+            ).as_str()
+        );
+
+        Promise::new(predecessor_account_id)
+            .transfer(near_amount)
+    }
+
+    fn internal_rebalance_liquidity_pool(&mut self) {
+        self.assert_operation_is_not_paused(PausableOperation::LiquidityPool);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_REBALANCE_LIQUIDITY_POOL);
+        self.assert_epoch_is_synchronized();
+
+        let current_account_id = env::current_account_id();
+
+        let taken_near_amount = match self.fund.liquidity_pool_delayed_withdrawal {
+            Some(ref delayed_withdrawal) if delayed_withdrawal.can_take_delayed_withdrawal(self.current_epoch_height, &self.config) => {
+                delayed_withdrawal.near_amount
+            }
+            _ => 0
+        };
+        if taken_near_amount > 0 {
+            self.fund.liquidity_pool_delayed_withdrawal = None;
+            self.fund.delayed_withdrawn_fund.balance -= taken_near_amount;
+            self.fund.classic_liquidity_balance += taken_near_amount;
+        }
+
+        let token_amount = self.liquidity_pool.token_amount;
+
+        let near_amount = self.convert_token_amount_to_near_amount(token_amount);
+
+        // Only one delayed withdrawal of the liquidity pool is pending at a time.
+        let requested_near_amount = if self.fund.liquidity_pool_delayed_withdrawal.is_none()
+            && near_amount > 0
+            && near_amount <= self.fund.classic_staked_balance {
+            self.fund.classic_staked_balance -= near_amount;
+            self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount += near_amount;
+            self.fungible_token.total_supply -= token_amount;
+            self.liquidity_pool.token_amount = 0;
+            self.fund.liquidity_pool_delayed_withdrawal = Some(
+                DelayedWithdrawal {
+                    near_amount,
+                    started_epoch_height: self.current_epoch_height
+                }
+            );
+
+            near_amount
+        } else {
+            0
+        };
+
+        if taken_near_amount == 0 && requested_near_amount == 0 {
+            env::panic_str("Liquidity pool is already balanced.");
+        }
+
+        env::log_str(
+            format!(
+                "
+                Liquidity pool of @{} rebalanced in {} epoch.
+                Taken from the delayed withdrawal amount is {} yoctoNear.
+                Requested to the delayed withdrawal amount is {} yoctoNear.
+                Sent to the delayed withdrawal amount is {} yoctoStNear.
+                New liquidity pool balance is {} yoctoNear.
+                ",
+                &current_account_id,
+                self.current_epoch_height,
+                taken_near_amount,
+                requested_near_amount,
+                if requested_near_amount > 0 { token_amount } else { 0 },
+                self.fund.classic_liquidity_balance
+            ).as_str()
+        );
+    }
+
+    fn internal_increase_validator_stake(&mut self, validator_account_id: AccountId, near_amount: Balance) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE);
+        self.assert_epoch_phase(EpochPhase::Distributing);
+        self.assert_authorized_management_by_role(Role::Operator);
+
+        if near_amount == 0 {
+            env::panic_str("Insufficient near amount.");
+        }
+        if near_amount > self.fund.classic_unstaked_balance {
+            env::panic_str("Near amount exceeded the available unstaked near balance.");
+        }
+
+        let validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if validator.is_only_for_investment {
+            env::panic_str("Validator is used only for investment purpose.");
+        }
+        if validator.is_retiring {
+            env::panic_str("Validator is retiring.");
+        }
+        if validator.is_underperforming(self.validating.minimum_validator_score) {
+            env::panic_str("Validator score is less than minimum validator score.");
+        }
+
+        match validator.staking_contract_version {
+            StakingContractVersion::Core => {
+                classic_validator::ext(validator_account_id.clone())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE)
+                    .with_attached_deposit(near_amount)
+                    .deposit_and_stake()
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE_CALLBACK)
+                            .increase_validator_stake_callback(validator_account_id, near_amount, env::epoch_height())
+                    )
+            }
+        }
+    }
+
+    /// The method is available for all users, so the Near amount is calculated by the pool, and the keeper can not
+    /// split the decrease into many rewarded calls.
+    fn internal_requested_decrease_validator_stake(&mut self, validator_account_id: AccountId, stake_decreasing_type: StakeDecreasingType) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE);
+        self.assert_epoch_phase(EpochPhase::UnstakingRequested);
+
+        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if validator.is_stake_decreasing {
+            env::panic_str("Validator stake decreasing is already in progress.");
+        }
+        let near_amount = match stake_decreasing_type {
+            StakeDecreasingType::Classic => {
+                validator.balance.classic_near_amount
+                    .min(self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount)
+            }
+            StakeDecreasingType::Investment => {
+                let investment_withdrawal = match self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
+                    Some(investment_withdrawal_) => investment_withdrawal_,
+                    None => {
+                        env::panic_str("Investment withdrawal account is not registered yet.");
+                    }
+                };
+
+                validator.balance.investment_near_amount
+                    .min(self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount)
+                    .min(investment_withdrawal.near_amount)
+            }
+        };
+        if near_amount == 0 {
+            env::panic_str("Validator stake decreasing is not needed.");
+        }
+
+        self.reserve_needed_to_request_near_amount(near_amount, &stake_decreasing_type);
+
+        validator.is_stake_decreasing = true;
+        self.validating.validator_registry.insert(&validator_account_id, &validator);
+
+        let current_account_id = env::current_account_id();
+
+        match validator.staking_contract_version {
+            StakingContractVersion::Core => {
+                classic_validator::ext(validator_account_id.clone())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST)
+                    .get_account_unstaked_balance(current_account_id.clone())
+                    .then(
+                        Self::ext(current_account_id)
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_1)
+                            .requested_decrease_validator_stake_callback_1(
+                                validator_account_id,
+                                near_amount,
+                                stake_decreasing_type,
+                                Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal),
+                                env::predecessor_account_id()
+                            )
+                    )
+            }
+        }
+    }
+
+    fn internal_take_unstaked_balance(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE);
+        self.assert_epoch_phase(EpochPhase::UpdatingValidators);
+
+        let current_epoch_height = env::epoch_height();
+
+        if !self.config.is_right_epoch(current_epoch_height) {
+            env::panic_str("Epoch is not intended for a take unstaked balance.");
+        }
+        match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator) => {
+                if validator.balance.get_unstaked_balance() == 0 {
+                    env::panic_str("Insufficient unstaked balance on validator.");
+                }
+                if validator.last_update_epoch_height >= current_epoch_height {
+                    env::panic_str("Validator is already updated.");
+                }
+
+                match validator.staking_contract_version {
+                    StakingContractVersion::Core => {
+                        classic_validator::ext(validator_account_id.clone())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_WITHDRAW)
+                            .withdraw(validator.balance.get_unstaked_balance().into())
+                            .then(
+                                Self::ext(env::current_account_id())
+                                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE_CALLBACK)
+                                    .take_unstaked_balance_callback(
+                                        validator_account_id,
+                                        validator.balance.requested_to_withdrawal_near_amount,
+                                        validator.balance.requested_to_restaking_near_amount,
+                                        validator.balance.requested_to_moving_near_amount,
+                                        env::predecessor_account_id()
+                                    )
+                            )
+                    }
+                }
+            }
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        }
+    }
+
+    fn internal_update_validator(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR);
+        self.assert_epoch_phase(EpochPhase::UpdatingValidators);
+
+        match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator) => {
+                let current_epoch_height = env::epoch_height();
+
+                if validator.last_update_epoch_height < current_epoch_height {
+                    let current_account_id = env::current_account_id();
+                    match validator.staking_contract_version {
+                        StakingContractVersion::Core => {
+                            classic_validator::ext(validator_account_id.clone())
+                                .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST)
+                                .get_account_total_balance(current_account_id.clone())
+                                .then(
+                                    Self::ext(current_account_id)
+                                        .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_CALLBACK)
+                                        .update_validator_callback(validator_account_id, current_epoch_height, env::predecessor_account_id())
+                                )
+                        }
+                    }
+                } else {
+                    env::panic_str("Validator is already updated.");
+                }
+            }
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        }
+    }
+
+    fn internal_update_validators(&mut self, from_index: Option<u64>, limit: Option<u64>) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_epoch_phase(EpochPhase::UpdatingValidators);
+
+        let current_epoch_height = env::epoch_height();
+
+        let current_account_id = env::current_account_id();
+
+        let account_id_registry = self.validating.validator_registry.keys_as_vector();
+
+        let validator_registry = self.validating.validator_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let limit_ = match limit {
+            Some(limit__) => std::cmp::min(limit__, MAXIMUM_QUANTITY_OF_VALIDATORS_IN_BATCH_UPDATE),
+            None => MAXIMUM_QUANTITY_OF_VALIDATORS_IN_BATCH_UPDATE
+        };
+
+        let to_index = std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len());
+
+        let mut validator_account_id_registry: Vec<AccountId> = vec![];
+
+        let mut promise: Option<Promise> = None;
+
+        let is_right_epoch = self.config.is_right_epoch(current_epoch_height);
+
+        let mut number_of_tgas_for_callback = NUMBER_OF_TGAS_FOR_UPDATE_VALIDATORS_CALLBACK;
+
+        for index in from_index_..to_index {
+            match (account_id_registry.get(index), validator_registry.get(index)) {
+                (Some(validator_account_id), Some(validator)) => {
+                    if validator.last_update_epoch_height >= current_epoch_height {
+                        continue;
+                    }
+
+                    let validator_promise = match validator.staking_contract_version {
+                        StakingContractVersion::Core => {
+                            classic_validator::ext(validator_account_id.clone())
+                                .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST)
+                                .with_unused_gas_weight(0)
+                                .get_account_total_balance(current_account_id.clone())
+                        }
+                    };
+
+                    promise = Some(
+                        match promise {
+                            Some(promise_) => promise_.and(validator_promise),
+                            None => validator_promise
+                        }
+                    );
+
+                    number_of_tgas_for_callback += NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING;
+                    if is_right_epoch
+                        && (validator.is_retiring || self.validating.redelegation_registry.get(&validator_account_id).is_some()) {
+                        number_of_tgas_for_callback += NUMBER_OF_TGAS_FOR_RESTAKING_UNSTAKE;
+                    }
+
+                    validator_account_id_registry.push(validator_account_id);
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        self.assert_gas_is_enough(
+            NUMBER_OF_TGAS_FOR_EXECUTION
+                + (NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES) * (validator_account_id_registry.len() as u64)
+                + number_of_tgas_for_callback
+                + NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        );
+
+        match promise {
+            Some(promise_) => {
+                promise_.then(
+                    Self::ext(current_account_id)
+                        .with_static_gas(Gas::ONE_TERA * number_of_tgas_for_callback)
+                        .update_validators_callback(validator_account_id_registry, current_epoch_height, env::predecessor_account_id())
+                )
+            }
+            None => {
+                env::panic_str("Validators are already updated.");
+            }
+        }
+    }
+
+    fn internal_update(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_phase(EpochPhase::Finalizing);
+
+        let current_epoch_height = env::epoch_height();
+
+        let common_balance_log = self.fund.get_common_balance();
+
+        let total_supply_log = self.fungible_token.total_supply;
+
+        self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount += self.fund.delayed_withdrawn_fund.queued_to_request_classic_near_amount;
+        self.fund.delayed_withdrawn_fund.queued_to_request_classic_near_amount = 0;
+
+        if self.validating.validators_quantity > 0 {
+            self.fund.classic_staked_balance += self.reward.previous_epoch_rewards_from_validators_near_amount;
+            self.validating.quantity_of_validators_updated_in_current_epoch = 0;
+            self.reward.total_rewards_from_validators_near_amount += self.reward.previous_epoch_rewards_from_validators_near_amount;
+
+            let (previous_epoch_rewards_from_validators_token_amount, _) = self.convert_near_amount_to_token_amount(
+                self.reward.previous_epoch_rewards_from_validators_near_amount
+            );
+
+            let mut reward_fee_self_log: Option<Fee> = None;
+
+            if let Some(ref reward_fee) = self.fee_registry.reward_fee {
+                reward_fee_self_log = Some(reward_fee.self_fee.clone());
+
+                let mut reward_fee_self_token_amount = reward_fee.self_fee.multiply(previous_epoch_rewards_from_validators_token_amount);
+                if reward_fee_self_token_amount != 0 {
+                    self.fungible_token.total_supply += reward_fee_self_token_amount;
+
+                    if let Some(ref reward_fee_partner) = reward_fee.partner_fee {
+                        let reward_fee_partner_token_amount = reward_fee_partner.multiply(reward_fee_self_token_amount);
+                        if reward_fee_partner_token_amount != 0 {
+                            reward_fee_self_token_amount -= reward_fee_partner_token_amount;
+
+                            let mut account_balance = match self.fungible_token.account_registry.get(&self.account_registry.partner_fee_receiver_account_id) {
+                                Some(account_balance_) => account_balance_,
+                                None => {
+                                    env::panic_str("Nonexecutable code. Object must exist.");
+                                }
+                            };
+                            account_balance.token_amount += reward_fee_partner_token_amount;
+
+                            self.fungible_token.account_registry.insert(&self.account_registry.partner_fee_receiver_account_id, &account_balance);
+                        }
+                    }
+
+                    let mut account_balance = match self.fungible_token.account_registry.get(&self.account_registry.self_fee_receiver_account_id) {
+                        Some(account_balance_) => account_balance_,
+                        None => {
+                            env::panic_str("Nonexecutable code. Object must exist.");
+                        }
+                    };
+                    account_balance.token_amount += reward_fee_self_token_amount;
+
+                    self.fungible_token.account_registry.insert(&self.account_registry.self_fee_receiver_account_id, &account_balance);
+                }
+            }
+
+            let current_account_id_log = env::current_account_id();
+            env::log_str(
+                format!(
+                    "
+                    Updating @{} pool from {} epoch to {} epoch.
+                    Old @{} total supply is {} yoctoStNear.
+                    Old @{} balance is {} yoctoNear.
+                    Received rewards from validators is {} yoctoNear.
+                    Fee is {:?}.
+                    Received token amount as fee is {}.
+                    New @{} balance is {} yoctoNear.
+                    New @{} total supply is {} yoctoStNear.
+                    ",
+                    current_account_id_log,
+                    current_epoch_height - 1,
+                    current_epoch_height,
+                    current_account_id_log,
+                    total_supply_log,
+                    current_account_id_log,
+                    common_balance_log,
+                    self.reward.previous_epoch_rewards_from_validators_near_amount,
+                    reward_fee_self_log,
+                    self.fungible_token.total_supply - total_supply_log,
+                    current_account_id_log,
+                    self.fund.get_common_balance(),
+                    current_account_id_log,
+                    self.fungible_token.total_supply
+                ).as_str()
+            );
+
+            self.reward.previous_epoch_rewards_from_validators_near_amount = 0;
+        }
+
+        self.fund.is_distributed_on_validators_in_current_epoch = false;
+        self.current_epoch_height = current_epoch_height;
+
+        self.pay_keeper_reward(env::predecessor_account_id());
+    }
+
+    fn internal_fund_keeper_budget(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+
+        self.keeper_reward.budget += env::attached_deposit();
+    }
+
+    fn internal_change_keeper_reward(&mut self, near_amount_per_call: Balance, maximum_near_amount_per_epoch: Balance) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::FeeAdmin);
+
+        if near_amount_per_call > maximum_near_amount_per_epoch {
+            env::panic_str("Near amount per call must be less than or equal to maximum Near amount per epoch.");
+        }
+
+        self.keeper_reward.near_amount_per_call = near_amount_per_call;
+        self.keeper_reward.maximum_near_amount_per_epoch = maximum_near_amount_per_epoch;
+    }
+
+    fn internal_add_validator(
+        &mut self,
+        validator_account_id: AccountId,
+        staking_contract_version: StakingContractVersion,
+        is_only_for_investment: bool,
+        is_preferred: bool,
+        refund_account_id: AccountId
+    ) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_by_role(Role::ValidatorAdmin);
+
+        if is_preferred && is_only_for_investment {
+            env::panic_str("Prefferred validator can not be only for investment.");
+        }
+
+        let attached_deposit = env::attached_deposit();
+
+        let storage_staking_price_per_additional_validator = Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator);
+        if attached_deposit < storage_staking_price_per_additional_validator {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        if self.validating.validator_registry.insert(
+            &validator_account_id,
+            &Validator::new(
+                staking_contract_version, is_only_for_investment, refund_account_id.clone(), storage_staking_price_per_additional_validator
+            )
+        ).is_some() {
+            env::panic_str("Validator account is already registered.");
+        }
+        self.validating.validators_quantity += 1;
+
+        if is_preferred {
+            self.validating.preffered_validator = Some(validator_account_id);
+        }
+
+        let near_amount = attached_deposit - storage_staking_price_per_additional_validator;
+        if near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(refund_account_id)
+                    .transfer(near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_remove_validator(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_by_role(Role::ValidatorAdmin);
+
+        let validator = match self.validating.validator_registry.remove(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if validator.balance.get_balance() > 0 {
+            env::panic_str("Validator has an available balance.");
+        }
+        if self.validating.redelegation_registry.get(&validator_account_id).is_some()
+            || self.validating.redelegation_registry.values().any(|redelegation| redelegation.target_validator_account_id == validator_account_id) {
+            env::panic_str("Validator has a pending redelegation.");
+        }
+
+        self.validating.validators_quantity -= 1;
+
+        if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
+            if *preffered_validator_account_id == validator_account_id {
+                self.validating.preffered_validator = None;
+            }
+        }
+
+        Self::refund_validator_metadata_storage_staking(&validator);
+
+        Promise::new(validator.storage_staking_payer_account_id)
+            .transfer(validator.storage_staking_price)
+    }
+
+    fn internal_change_validator_investment_context(&mut self, validator_account_id: AccountId, is_only_for_investment: bool) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+
+        if validator.is_only_for_investment == is_only_for_investment {
+            env::panic_str("Changing the state to the same state.");
+        }
+
+        if is_only_for_investment {
+            if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
+                if *preffered_validator_account_id == validator_account_id {
+                    env::panic_str("Prefferred validator can not be only for investment.");
+                }
+            }
+
+            if validator.balance.classic_near_amount > 0 {
+                env::panic_str("Validator classic staked balance is not equal to zero.");
+            }
+        }
+
+        validator.is_only_for_investment = is_only_for_investment;
+        self.validating.validator_registry.insert(&validator_account_id, &validator);
+    }
+
+    fn internal_change_minimum_validator_score(&mut self, minimum_validator_score: Option<u64>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        if self.validating.minimum_validator_score == minimum_validator_score {
+            env::panic_str("Changing the state to the same state.");
+        }
+
+        self.validating.minimum_validator_score = minimum_validator_score;
+    }
+
+    fn internal_change_preffered_validator(&mut self, validator_account_id: Option<AccountId>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        match validator_account_id {
+            Some(validator_account_id_) => {
+                let validator = match self.validating.validator_registry.get(&validator_account_id_) {
+                    Some(validator_) => validator_,
+                    None => {
+                        env::panic_str("Validator account is not registered yet.");
+                    }
+                };
+
+                if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
+                    if *preffered_validator_account_id == validator_account_id_ {
+                        env::panic_str("Changing the state to the same state.");
+                    }
+                }
+
+                if validator.is_only_for_investment {
+                    env::panic_str("Prefferred validator can not be only for investment.");
+                }
+                if validator.is_retiring {
+                    env::panic_str("Prefferred validator can not be retiring.");
+                }
+                if validator.is_underperforming(self.validating.minimum_validator_score) {
+                    env::panic_str("Prefferred validator can not be underperforming.");
+                }
+
+                self.validating.preffered_validator = Some(validator_account_id_);
+            }
+            None => {
+                if self.validating.preffered_validator.is_none() {
+                    env::panic_str("Changing the state to the same state.");
+                }
+
+                self.validating.preffered_validator = None;
+            }
+        }
+    }
+
+    fn internal_change_validator_metadata(
+        &mut self,
+        validator_account_id: AccountId,
+        name: Option<String>,
+        url: Option<String>,
+        commission: Option<Fee>
+    ) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        if let Some(ref name_) = name {
+            if name_.is_empty() || name_.chars().count() > MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_NAME {
+                env::panic_str("Validator name is not valid.");
+            }
+        }
+        if let Some(ref url_) = url {
+            if url_.is_empty() || url_.chars().count() > MAXIMUM_NUMBER_OF_CHARACTERS_IN_VALIDATOR_URL {
+                env::panic_str("Validator url is not valid.");
+            }
+        }
+        if let Some(ref commission_) = commission {
+            if commission_.denominator == 0 || commission_.numerator > commission_.denominator {
+                env::panic_str("Validator commission is not valid.");
+            }
+        }
+
+        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+
+        validator.metadata.name = name;
+        validator.metadata.url = url;
+        validator.metadata.commission = commission;
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        // The caller pays the storage staking of the whole new metadata, and the previous payer gets its payment back.
+        let mut available_near_amount = env::attached_deposit();
+        let mut previous_payer_refund: Option<(AccountId, Balance)> = None;
+        if let Some(previous_payer_account_id) = validator.metadata_storage_staking_payer_account_id.take() {
+            if previous_payer_account_id == predecessor_account_id {
+                available_near_amount += validator.metadata_storage_staking_price;
+            } else if validator.metadata_storage_staking_price > 0 {
+                previous_payer_refund = Some((previous_payer_account_id, validator.metadata_storage_staking_price));
+            }
+        }
+
+        let storage_staking_price_per_metadata = Self::calculate_storage_staking_price(validator.metadata.calculate_additional_storage_usage());
+        if available_near_amount < storage_staking_price_per_metadata {
+            env::panic_str("Insufficient near deposit.");
+        }
+        let refundable_near_amount = available_near_amount - storage_staking_price_per_metadata;
+
+        if storage_staking_price_per_metadata > 0 {
+            validator.metadata_storage_staking_payer_account_id = Some(predecessor_account_id.clone());
+        }
+        validator.metadata_storage_staking_price = storage_staking_price_per_metadata;
+
+        self.validating.validator_registry.insert(&validator_account_id, &validator);
+
+        if let Some((previous_payer_account_id, previous_payer_refundable_near_amount)) = previous_payer_refund {
+            Promise::new(previous_payer_account_id)
+                .transfer(previous_payer_refundable_near_amount);
+        }
+
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_update_validator_commission(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::Operator);
+
+        let validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+
+        match validator.staking_contract_version {
+            StakingContractVersion::Core => {
+                classic_validator::ext(validator_account_id.clone())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_FEE_REQUEST)
+                    .get_reward_fee_fraction()
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION_CALLBACK)
+                            .update_validator_commission_callback(validator_account_id)
+                    )
+            }
+        }
+    }
+
+    fn internal_redelegate(
+        &mut self,
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: Balance
+    ) -> PromiseOrValue<()> {
+        self.assert_operation_is_not_paused(PausableOperation::ValidatorOperations);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        if near_amount == 0 {
+            env::panic_str("Insufficient near amount.");
+        }
+        if source_validator_account_id == target_validator_account_id {
+            env::panic_str("Source and target validators must be different.");
+        }
+
+        let source_validator = match self.validating.validator_registry.get(&source_validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if source_validator.is_retiring {
+            env::panic_str("Validator is retiring.");
+        }
+        if source_validator.balance.classic_near_amount < near_amount {
+            env::panic_str("Insufficient classic Near amount on validator.");
+        }
+
+        let target_validator = match self.validating.validator_registry.get(&target_validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if target_validator.is_only_for_investment {
+            env::panic_str("Validator is used only for investment purpose.");
+        }
+        if target_validator.is_retiring {
+            env::panic_str("Validator is retiring.");
+        }
+
+        let storage_staking_price_per_additional_redelegation = Self::calculate_storage_staking_price(self.validating.storage_usage_per_redelegation);
+        if env::attached_deposit() < storage_staking_price_per_additional_redelegation {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        if self.validating.redelegation_registry.insert(
+            &source_validator_account_id,
+            &Redelegation {
+                target_validator_account_id: target_validator_account_id.clone(),
+                near_amount,
+                unstaked_near_amount: 0,
+                account_id: env::predecessor_account_id()
+            }
+        ).is_some() {
+            env::panic_str("Redelegation from validator is already requested.");
+        }
+
+        env::log_str(
+            format!(
+                "
+                Requested redelegation from validator @{} to validator @{} in {} epoch.
+                Redelegated Near amount is {} yoctoNear.
+                ",
+                source_validator_account_id,
+                target_validator_account_id,
+                self.current_epoch_height,
+                near_amount
+            ).as_str()
+        );
+
+        let near_amount_ = env::attached_deposit() - storage_staking_price_per_additional_redelegation;
+        if near_amount_ > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(env::predecessor_account_id())
+                    .transfer(near_amount_)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_retire_validator(&mut self, validator_account_id: AccountId) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Validator account is not registered yet.");
+            }
+        };
+        if validator.is_retiring {
+            env::panic_str("Changing the state to the same state.");
+        }
+        if self.validating.redelegation_registry.get(&validator_account_id).is_some() {
+            env::panic_str("Validator has a pending redelegation.");
+        }
+
+        validator.is_retiring = true;
+        self.validating.validator_registry.insert(&validator_account_id, &validator);
+
+        if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
+            if *preffered_validator_account_id == validator_account_id {
+                self.validating.preffered_validator = None;
+            }
+        }
+
+        env::log_str(
+            format!(
+                "
+                Retiring validator @{} in {} epoch.
+                Classic Near amount on validator is {} yoctoNear.
+                Investment Near amount on validator is {} yoctoNear, it should be migrated by investors.
+                ",
+                validator_account_id,
+                self.current_epoch_height,
+                validator.balance.classic_near_amount,
+                validator.balance.investment_near_amount
+            ).as_str()
+        );
+    }
+
+    fn internal_propose_validator(&mut self, validator_account_id: AccountId, staking_contract_version: StakingContractVersion) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_epoch_is_synchronized();
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        if !self.validating.investor_investment_registry.contains_key(&predecessor_account_id) {
+            env::panic_str("Investor account is not registered yet.");
+        }
+        if self.validating.validator_registry.get(&validator_account_id).is_some() {
+            env::panic_str("Validator account is already registered.");
+        }
+        if self.investor_registry.validator_proposal_registry.get(&validator_account_id).is_some() {
+            env::panic_str("Validator account is already proposed.");
+        }
+
+        let mut validator_proposal = ValidatorProposal {
+            investor_account_id: predecessor_account_id.clone(),
+            staking_contract_version,
+            near_amount: Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator),
+            storage_staking_price: 0
+        };
+
+        let initial_storage_usage = env::storage_usage();
+
+        self.investor_registry.validator_proposal_registry.insert(&validator_account_id, &validator_proposal);
+
+        validator_proposal.storage_staking_price = Self::calculate_storage_staking_price(env::storage_usage() - initial_storage_usage);
+        self.investor_registry.validator_proposal_registry.insert(&validator_account_id, &validator_proposal);
+
+        let required_near_amount = validator_proposal.near_amount + validator_proposal.storage_staking_price;
+        if env::attached_deposit() < required_near_amount {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        let refundable_near_amount = env::attached_deposit() - required_near_amount;
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_cancel_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        let validator_proposal = self.get_validator_proposal(&validator_account_id);
+        if env::predecessor_account_id() != validator_proposal.investor_account_id {
+            env::panic_str("Validator proposal can be cancelled only by the proposer.");
+        }
+
+        self.investor_registry.validator_proposal_registry.remove(&validator_account_id);
+
+        Promise::new(validator_proposal.investor_account_id)
+            .transfer(validator_proposal.near_amount + validator_proposal.storage_staking_price)
+    }
+
+    /// The validator is added to the allowed validators of the investor, if the investor has them.
+    /// The storage staking of the allowed validator is taken from the storage staking of the proposal.
+    fn internal_approve_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_by_role(Role::ValidatorAdmin);
+
+        let validator_proposal = self.get_validator_proposal(&validator_account_id);
+
+        self.investor_registry.validator_proposal_registry.remove(&validator_account_id);
+
+        if self.validating.validator_registry.insert(
+            &validator_account_id,
+            &Validator::new(
+                validator_proposal.staking_contract_version, true, validator_proposal.investor_account_id.clone(), validator_proposal.near_amount
+            )
+        ).is_some() {
+            env::panic_str("Validator account is already registered.");
+        }
+        self.validating.validators_quantity += 1;
+
+        let mut refundable_near_amount = validator_proposal.storage_staking_price;
+
+        if let Some(mut investor_setting) = self.investor_registry.investor_setting_registry.get(&validator_proposal.investor_account_id) {
+            let old_additional_storage_usage = investor_setting.calculate_additional_storage_usage();
+
+            if let Some(ref mut allowed_validator_account_ids) = investor_setting.allowed_validator_account_ids {
+                allowed_validator_account_ids.push(validator_account_id);
+
+                let storage_staking_price_per_additional_allowed_validator = Self::calculate_storage_staking_price(
+                    investor_setting.calculate_additional_storage_usage() - old_additional_storage_usage
+                );
+                if refundable_near_amount < storage_staking_price_per_additional_allowed_validator {
+                    env::panic_str("Nonexecutable code. Proposal storage staking must cover the allowed validator.");
+                }
+                refundable_near_amount -= storage_staking_price_per_additional_allowed_validator;
+
+                self.investor_registry.investor_setting_registry.insert(&validator_proposal.investor_account_id, &investor_setting);
+            }
+        }
+
+        Promise::new(validator_proposal.investor_account_id)
+            .transfer(refundable_near_amount)
+    }
+
+    fn internal_reject_validator_proposal(&mut self, validator_account_id: AccountId) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management_by_role(Role::ValidatorAdmin);
+
+        let validator_proposal = self.get_validator_proposal(&validator_account_id);
+
+        self.investor_registry.validator_proposal_registry.remove(&validator_account_id);
+
+        Promise::new(validator_proposal.investor_account_id)
+            .transfer(validator_proposal.near_amount + validator_proposal.storage_staking_price)
+    }
+
+    fn get_validator_proposal(&self, validator_account_id: &AccountId) -> ValidatorProposal {
+        match self.investor_registry.validator_proposal_registry.get(validator_account_id) {
+            Some(validator_proposal_) => validator_proposal_,
+            None => {
+                env::panic_str("Validator proposal does not exist.");
+            }
+        }
+    }
+
+    fn internal_add_investor(&mut self, investor_account_id: AccountId) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::InvestorAdmin);
+
+        if self.investor_registry.investor_setting_registry.get(&investor_account_id).is_some() {
+            env::panic_str("Investor account is already registered.");
+        }
+
+        let mut storage_staking_price = Self::calculate_storage_staking_price(self.investor_registry.storage_usage_per_investor);
+
+        // The investor registered before the investor registry was added is only listed.
+        if !self.validating.investor_investment_registry.contains_key(&investor_account_id) {
+            storage_staking_price += Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment);
+
+            self.validating.investor_investment_registry.insert(&investor_account_id, &InvestorInvestment::new(investor_account_id.clone()));
+        }
+
+        if env::attached_deposit() < storage_staking_price {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        self.investor_registry.investor_setting_registry.insert(
+            &investor_account_id, &InvestorSetting::new(env::predecessor_account_id(), storage_staking_price)
+        );
+
+        let near_amount = env::attached_deposit() - storage_staking_price;
+        if near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(env::predecessor_account_id())
+                    .transfer(near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_remove_investor(&mut self, investor_account_id: AccountId, validator_account_ids: Vec<AccountId>) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::InvestorAdmin);
+
+        let mut investor_investment = match self.validating.investor_investment_registry.remove(&investor_account_id) {
+            Some(investor_investment_) => investor_investment_,
+            None => {
+                env::panic_str("Investor account is not registered yet.");
+            }
+        };
+
+        let released_storage_staking_price = self.remove_empty_distributions(&mut investor_investment, &validator_account_ids);
+        if investor_investment.staked_balance > 0 {
+            env::panic_str("Investor has an available balance.");
+        }
+        if investor_investment.distributions_quantity > 0 {
+            env::panic_str("Not all validators with the investor distributions are listed.");
+        }
+        if self.validating.investment_move_registry.get(&investor_account_id).is_some() {
+            env::panic_str("Investment move is not completed yet.");
+        }
+        if released_storage_staking_price > 0 {
+            Promise::new(investor_account_id.clone())
+                .transfer(released_storage_staking_price);
+        }
+
+        self.refund_investor_registration(&investor_account_id, env::predecessor_account_id())
+    }
+
+    fn internal_change_investor_setting(
+        &mut self,
+        investor_account_id: AccountId,
+        maximum_staked_balance: Option<Balance>,
+        allowed_validator_account_ids: Option<Vec<AccountId>>,
+        deposit_fee: Option<Fee>
+    ) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_by_role(Role::InvestorAdmin);
+
+        if let Some(ref allowed_validator_account_ids_) = allowed_validator_account_ids {
+            for (index, validator_account_id) in allowed_validator_account_ids_.iter().enumerate() {
+                if self.validating.validator_registry.get(validator_account_id).is_none() {
+                    env::panic_str("Validator account is not registered yet.");
+                }
+                if allowed_validator_account_ids_[..index].contains(validator_account_id) {
+                    env::panic_str("Allowed validators must be unique.");
+                }
+            }
+        }
+        if let Some(ref deposit_fee_) = deposit_fee {
+            deposit_fee_.assert_valid();
+        }
+
+        let mut investor_setting = match self.investor_registry.investor_setting_registry.get(&investor_account_id) {
+            Some(investor_setting_) => investor_setting_,
+            None => {
+                env::panic_str("Investor account is not registered yet.");
+            }
+        };
+
+        let old_additional_storage_usage = investor_setting.calculate_additional_storage_usage();
+
+        investor_setting.maximum_staked_balance = maximum_staked_balance;
+        investor_setting.allowed_validator_account_ids = allowed_validator_account_ids;
+        investor_setting.deposit_fee = deposit_fee;
+
+        let new_additional_storage_usage = investor_setting.calculate_additional_storage_usage();
+
+        let attached_deposit = env::attached_deposit();
+
+        let refundable_near_amount = if new_additional_storage_usage > old_additional_storage_usage {
+            let storage_staking_price_per_additional_setting =
+                Self::calculate_storage_staking_price(new_additional_storage_usage - old_additional_storage_usage);
+            if attached_deposit < storage_staking_price_per_additional_setting {
+                env::panic_str("Insufficient near deposit.");
+            }
+
+            attached_deposit - storage_staking_price_per_additional_setting
+        } else {
+            attached_deposit + Self::calculate_storage_staking_price(old_additional_storage_usage - new_additional_storage_usage)
+        };
+
+        self.investor_registry.investor_setting_registry.insert(&investor_account_id, &investor_setting);
+
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(env::predecessor_account_id())
+                    .transfer(refundable_near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_propose_owner(&mut self, owner_id: AccountId) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management_only_by_owner();
+
+        if owner_id == self.account_registry.owner_id {
+            env::panic_str("Account is already the owner.");
+        }
+
+        self.account_registry.pending_owner_id = Some(owner_id);
+    }
+
+    fn internal_accept_owner(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        if self.account_registry.pending_owner_id.as_ref() != Some(&predecessor_account_id) {
+            env::panic_str("Account is not proposed to become the owner.");
+        }
+
+        self.account_registry.owner_id = predecessor_account_id;
+        self.account_registry.pending_owner_id = None;
+    }
+
+    fn internal_cancel_owner_proposal(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management_only_by_owner();
+
+        if self.account_registry.pending_owner_id.take().is_none() {
+            env::panic_str("Owner proposal does not exist.");
+        }
+    }
+
+    fn internal_propose_manager(&mut self, manager_id: AccountId) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management();
+
+        if manager_id == self.account_registry.manager_id {
+            env::panic_str("Account is already the manager.");
+        }
+
+        self.account_registry.pending_manager_id = Some(manager_id);
+    }
+
+    fn internal_accept_manager(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        if self.account_registry.pending_manager_id.as_ref() != Some(&predecessor_account_id) {
+            env::panic_str("Account is not proposed to become the manager.");
+        }
+
+        self.account_registry.manager_id = predecessor_account_id;
+        self.account_registry.pending_manager_id = None;
+    }
+
+    fn internal_cancel_manager_proposal(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management();
+
+        if self.account_registry.pending_manager_id.take().is_none() {
+            env::panic_str("Manager proposal does not exist.");
+        }
+    }
+
+    fn internal_change_reward_fee(&mut self, reward_fee_self: Option<Fee>, reward_fee_partner: Option<Fee>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_by_role(Role::FeeAdmin);
+
+        if reward_fee_self.is_none() && reward_fee_partner.is_some() {
+            env::panic_str("Reward fees are not valid.");
+        }
+        self.fee_registry.reward_fee = if let Some(reward_fee_self_) = reward_fee_self {
+            reward_fee_self_.assert_valid();
 
             if let Some(ref reward_fee_partner) = reward_fee_partner {
                 reward_fee_partner.assert_valid();
             }
 
-            Some (
-                SharedFee {
-                    self_fee: reward_fee_self_,
-                    partner_fee: reward_fee_partner
+            Some (
+                SharedFee {
+                    self_fee: reward_fee_self_,
+                    partner_fee: reward_fee_partner
+                }
+            )
+        } else {
+            None
+        };
+    }
+
+    fn internal_change_instant_withdraw_fee(&mut self, instant_withdraw_fee_self: Option<Fee>, instant_withdraw_fee_partner: Option<Fee>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_by_role(Role::FeeAdmin);
+
+        if instant_withdraw_fee_self.is_none() && instant_withdraw_fee_partner.is_some() {
+            env::panic_str("Instant withdraw fees are not valid.");
+        }
+        self.fee_registry.instant_withdraw_fee = if let Some(instant_withdraw_fee_self_) = instant_withdraw_fee_self {
+            instant_withdraw_fee_self_.assert_valid();
+
+            if let Some(ref instant_withdraw_fee_partner) = instant_withdraw_fee_partner {
+                instant_withdraw_fee_partner.assert_valid();
+            }
+
+            Some (
+                SharedFee {
+                    self_fee: instant_withdraw_fee_self_,
+                    partner_fee: instant_withdraw_fee_partner
+                }
+            )
+        } else {
+            None
+        };
+    }
+
+    fn internal_change_fee_receivers(
+        &mut self,
+        self_fee_receiver_account_id: AccountId,
+        partner_fee_receiver_account_id: AccountId,
+        is_token_sweeping: bool,
+        refund_account_id: AccountId
+    ) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_sensitive_management_only_by_owner();
+
+        if self_fee_receiver_account_id == partner_fee_receiver_account_id {
+            env::panic_str("Fee receivers must be different.");
+        }
+
+        let attached_deposit = env::attached_deposit();
+
+        let storage_staking_price_per_additional_accounts =
+            self.calculate_storage_staking_price_per_additional_fee_receivers(&self_fee_receiver_account_id, &partner_fee_receiver_account_id);
+        if attached_deposit < storage_staking_price_per_additional_accounts {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        for fee_receiver_account_id in [&self_fee_receiver_account_id, &partner_fee_receiver_account_id] {
+            if self.fungible_token.account_registry.get(fee_receiver_account_id).is_none() {
+                self.fungible_token.account_registry.insert(
+                    fee_receiver_account_id,
+                    &AccountBalance {
+                        token_amount: 0,
+                        classic_near_amount: 0,
+                        investment_near_amount: 0
+                    }
+                );
+                self.fungible_token.accounts_quantity += 1;
+            }
+        }
+
+        let previous_self_fee_receiver_account_id = self.account_registry.self_fee_receiver_account_id.clone();
+        let previous_partner_fee_receiver_account_id = self.account_registry.partner_fee_receiver_account_id.clone();
+
+        self.account_registry.self_fee_receiver_account_id = self_fee_receiver_account_id.clone();
+        self.account_registry.partner_fee_receiver_account_id = partner_fee_receiver_account_id.clone();
+
+        for (previous_fee_receiver_account_id, fee_receiver_account_id) in [
+            (previous_self_fee_receiver_account_id, self_fee_receiver_account_id),
+            (previous_partner_fee_receiver_account_id, partner_fee_receiver_account_id)
+        ] {
+            // The account that remains a fee receiver keeps its token balance and protection.
+            if self.is_protected_account(&previous_fee_receiver_account_id) {
+                continue;
+            }
+
+            let mut previous_account_balance = match self.fungible_token.account_registry.get(&previous_fee_receiver_account_id) {
+                Some(previous_account_balance_) => previous_account_balance_,
+                None => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            };
+
+            if is_token_sweeping && previous_account_balance.token_amount > 0 {
+                if self.validating.investor_investment_registry.get(&previous_fee_receiver_account_id).is_some() {
+                    env::panic_str("Token balance of the investor can not be swept.");
+                }
+
+                let mut account_balance = match self.fungible_token.account_registry.get(&fee_receiver_account_id) {
+                    Some(account_balance_) => account_balance_,
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                };
+                account_balance.token_amount += previous_account_balance.token_amount;
+                previous_account_balance.token_amount = 0;
+
+                self.fungible_token.account_registry.insert(&fee_receiver_account_id, &account_balance);
+            }
+
+            if previous_account_balance.token_amount == 0
+                && previous_account_balance.classic_near_amount == 0
+                && previous_account_balance.investment_near_amount == 0 {
+                self.fungible_token.account_registry.remove(&previous_fee_receiver_account_id);
+                self.fungible_token.accounts_quantity -= 1;
+
+                Promise::new(previous_fee_receiver_account_id)
+                    .transfer(Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account));
+            } else {
+                self.fungible_token.account_registry.insert(&previous_fee_receiver_account_id, &previous_account_balance);
+            }
+        }
+
+        let near_amount = attached_deposit - storage_staking_price_per_additional_accounts;
+        if near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(refund_account_id)
+                    .transfer(near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_change_epoch_quantities(&mut self, epoch_quantity_for_delayed_withdrawal: u64, epoch_quantity_for_validator_unstake: u64) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_only_by_owner();
+
+        Config::assert_valid_epoch_quantities(epoch_quantity_for_delayed_withdrawal, epoch_quantity_for_validator_unstake);
+
+        self.config.epoch_quantity_for_delayed_withdrawal = epoch_quantity_for_delayed_withdrawal;
+        self.config.epoch_quantity_for_validator_unstake = epoch_quantity_for_validator_unstake;
+    }
+
+    fn internal_change_minimum_number_of_tgas(&mut self, minimum_number_of_tgas: u64) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_only_by_owner();
+
+        Config::assert_valid_minimum_number_of_tgas(minimum_number_of_tgas);
+
+        self.config.minimum_number_of_tgas = minimum_number_of_tgas;
+    }
+
+    fn internal_change_minimum_deposit_amount(&mut self, minimum_deposit_amount: Balance) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_is_synchronized();
+        self.assert_authorized_management_only_by_owner();
+
+        // The deposit must cover the storage staking of the new account and the new investor distribution.
+        let maximum_storage_staking_price = Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account)
+            + Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
+        if minimum_deposit_amount <= maximum_storage_staking_price {
+            env::panic_str("Minimum deposit amount must be greater than storage staking price of additional accounts.");
+        }
+
+        self.config.minimum_deposit_amount = minimum_deposit_amount;
+    }
+
+    fn internal_grant_role(&mut self, role: Role, account_id: AccountId) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_authorized_management_only_by_owner();
+
+        let storage_staking_price_per_additional_role_account = Self::calculate_storage_staking_price(self.role_registry.storage_usage_per_role_account);
+        if env::attached_deposit() < storage_staking_price_per_additional_role_account {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        if !self.role_registry.get_mut(role).insert(&account_id) {
+            env::panic_str("Account already has the role.");
+        }
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        Event::RoleGrant(
+            &[
+                RoleData {
+                    role,
+                    account_id: &account_id,
+                    owner_id: &predecessor_account_id
+                }
+            ]
+        ).emit();
+
+        let near_amount = env::attached_deposit() - storage_staking_price_per_additional_role_account;
+        if near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_revoke_role(&mut self, role: Role, account_id: AccountId) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management_only_by_owner();
+
+        if !self.role_registry.get_mut(role).remove(&account_id) {
+            env::panic_str("Account does not have the role.");
+        }
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        Event::RoleRevoke(
+            &[
+                RoleData {
+                    role,
+                    account_id: &account_id,
+                    owner_id: &predecessor_account_id
+                }
+            ]
+        ).emit();
+
+        let near_amount = Self::calculate_storage_staking_price(self.role_registry.storage_usage_per_role_account);
+
+        Promise::new(predecessor_account_id)
+            .transfer(near_amount)
+    }
+
+    fn internal_pause(&mut self, operation: PausableOperation) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_pause_management();
+
+        if self.pause.is_paused(operation) {
+            env::panic_str("Operation is already paused.");
+        }
+
+        self.pause.set_paused(operation, true);
+
+        Event::Pause(
+            &[
+                PauseData {
+                    operation,
+                    account_id: &env::predecessor_account_id()
+                }
+            ]
+        ).emit();
+    }
+
+    fn internal_unpause(&mut self, operation: PausableOperation) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_management_only_by_owner();
+
+        if !self.pause.is_paused(operation) {
+            env::panic_str("Operation is not paused.");
+        }
+
+        self.pause.set_paused(operation, false);
+
+        Event::Unpause(
+            &[
+                PauseData {
+                    operation,
+                    account_id: &env::predecessor_account_id()
+                }
+            ]
+        ).emit();
+    }
+
+    fn internal_change_council(&mut self, member_account_ids: Vec<AccountId>, threshold: u64, execution_delay_epoch_quantity: u64) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_authorized_sensitive_management_only_by_owner();
+
+        Council::assert_valid_members(&member_account_ids, threshold);
+
+        self.council.member_account_ids = member_account_ids;
+        self.council.threshold = threshold;
+        self.council.execution_delay_epoch_quantity = execution_delay_epoch_quantity;
+    }
+
+    fn internal_create_proposal(&mut self, action: ProposalAction) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_council_member();
+
+        // The Near amount attached to the action execution.
+        let near_amount = match action {
+            ProposalAction::AddValidator { .. } => Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator),
+            ProposalAction::ChangeFeeReceivers { ref self_fee_receiver_account_id, ref partner_fee_receiver_account_id, .. } => {
+                self.calculate_storage_staking_price_per_additional_fee_receivers(self_fee_receiver_account_id, partner_fee_receiver_account_id)
+            }
+            _ => 0
+        };
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        let proposal_id = self.council.next_proposal_id;
+
+        let mut proposal = Proposal {
+            proposer_account_id: predecessor_account_id.clone(),
+            action,
+            near_amount,
+            storage_staking_price: 0,
+            confirmation_registry: vec![predecessor_account_id.clone()],
+            executable_epoch_height: None
+        };
+        if self.council.is_threshold_reached(&proposal) {
+            proposal.executable_epoch_height = Some(self.council.calculate_executable_epoch_height(env::epoch_height()));
+        }
+
+        let initial_storage_usage = env::storage_usage();
+
+        self.council.proposal_registry.insert(&proposal_id, &proposal);
+
+        // The proposal size depends on the action, so the storage usage is measured for each proposal.
+        proposal.storage_staking_price = Self::calculate_storage_staking_price(env::storage_usage() - initial_storage_usage);
+        self.council.proposal_registry.insert(&proposal_id, &proposal);
+        self.council.next_proposal_id += 1;
+
+        let required_near_amount = near_amount + proposal.storage_staking_price;
+        if env::attached_deposit() < required_near_amount {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        Event::ProposalCreate(
+            &[
+                ProposalData {
+                    proposal_id,
+                    account_id: &predecessor_account_id
+                }
+            ]
+        ).emit();
+
+        let refundable_near_amount = env::attached_deposit() - required_near_amount;
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_confirm_proposal(&mut self, proposal_id: u64) -> PromiseOrValue<()> {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+        self.assert_council_member();
+
+        let mut proposal = self.get_proposal(proposal_id);
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        if proposal.confirmation_registry.contains(&predecessor_account_id) {
+            env::panic_str("Proposal is already confirmed by the account.");
+        }
+
+        let storage_staking_price_per_additional_confirmation = Self::calculate_storage_staking_price(self.council.storage_usage_per_confirmation);
+        if env::attached_deposit() < storage_staking_price_per_additional_confirmation {
+            env::panic_str("Insufficient near deposit.");
+        }
+
+        proposal.confirmation_registry.push(predecessor_account_id.clone());
+        if proposal.executable_epoch_height.is_none() && self.council.is_threshold_reached(&proposal) {
+            proposal.executable_epoch_height = Some(self.council.calculate_executable_epoch_height(env::epoch_height()));
+        }
+
+        Event::ProposalConfirm(
+            &[
+                ProposalData {
+                    proposal_id,
+                    account_id: &predecessor_account_id
+                }
+            ]
+        ).emit();
+
+        let refundable_near_amount = env::attached_deposit() - storage_staking_price_per_additional_confirmation;
+
+        if self.is_proposal_executable(&proposal) {
+            // The confirmation storage staking is refunded on the execution together with the other confirmations.
+            if refundable_near_amount > 0 {
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount);
+            }
+
+            return PromiseOrValue::Promise(self.execute_proposal_action(proposal_id, proposal));
+        }
+
+        self.council.proposal_registry.insert(&proposal_id, &proposal);
+
+        if refundable_near_amount > 0 {
+            return PromiseOrValue::Promise(
+                Promise::new(predecessor_account_id)
+                    .transfer(refundable_near_amount)
+            );
+        }
+
+        PromiseOrValue::Value(())
+    }
+
+    fn internal_execute_proposal(&mut self, proposal_id: u64) -> Promise {
+        let proposal = self.get_proposal(proposal_id);
+
+        if !self.council.is_threshold_reached(&proposal) {
+            env::panic_str("Proposal does not have enough confirmations.");
+        }
+        if !self.is_proposal_executable(&proposal) {
+            env::panic_str("Proposal execution delay has not passed yet.");
+        }
+
+        self.execute_proposal_action(proposal_id, proposal)
+    }
+
+    fn internal_cancel_proposal(&mut self, proposal_id: u64) -> Promise {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        let proposal = self.get_proposal(proposal_id);
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        if predecessor_account_id != proposal.proposer_account_id {
+            env::panic_str("Proposal can be cancelled only by the proposer.");
+        }
+
+        self.council.proposal_registry.remove(&proposal_id);
+
+        self.refund_proposal_confirmations(&proposal);
+
+        Event::ProposalCancel(
+            &[
+                ProposalData {
+                    proposal_id,
+                    account_id: &predecessor_account_id
+                }
+            ]
+        ).emit();
+
+        Promise::new(predecessor_account_id)
+            .transfer(proposal.storage_staking_price + proposal.near_amount)
+    }
+
+    /// The proposal is removed before the action execution, so the failed action does not leave the proposal.
+    /// The action is executed by the pool itself, which passes the authorization of the sensitive management.
+    /// The proposer is passed with the action, so the Near amount that the action returns is refunded to the proposer.
+    fn execute_proposal_action(&mut self, proposal_id: u64, proposal: Proposal) -> Promise {
+        let number_of_tgas = proposal.action.get_number_of_tgas().max(self.config.minimum_number_of_tgas);
+
+        self.assert_gas_is_enough(
+            NUMBER_OF_TGAS_FOR_EXECUTION + number_of_tgas + NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES
+        );
+
+        self.council.proposal_registry.remove(&proposal_id);
+
+        self.refund_proposal_confirmations(&proposal);
+
+        Promise::new(proposal.proposer_account_id.clone())
+            .transfer(proposal.storage_staking_price);
+
+        Event::ProposalExecute(
+            &[
+                ProposalData {
+                    proposal_id,
+                    account_id: &env::predecessor_account_id()
+                }
+            ]
+        ).emit();
+
+        let action_promise = match proposal.action {
+            ProposalAction::Upgrade { code } => {
+                Promise::new(env::current_account_id())
+                    .function_call(
+                        "upgrade".to_string(),
+                        code.0,
+                        proposal.near_amount,
+                        Gas::ONE_TERA * number_of_tgas
+                    )
+            }
+            action => {
+                Self::ext(env::current_account_id())
+                    .with_attached_deposit(proposal.near_amount)
+                    .with_static_gas(Gas::ONE_TERA * number_of_tgas)
+                    .carry_out_proposal_action(action, proposal.proposer_account_id.clone())
+            }
+        };
+
+        action_promise
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_EXECUTE_PROPOSAL_CALLBACK)
+                    .execute_proposal_callback(proposal_id, proposal.proposer_account_id, proposal.near_amount)
+            )
+    }
+
+    /// Refunds the storage staking of confirmations to all confirmed accounts except the proposer.
+    fn refund_proposal_confirmations(&self, proposal: &Proposal) {
+        let storage_staking_price_per_confirmation = Self::calculate_storage_staking_price(self.council.storage_usage_per_confirmation);
+
+        for account_id in proposal.confirmation_registry.iter() {
+            if *account_id != proposal.proposer_account_id {
+                Promise::new(account_id.clone())
+                    .transfer(storage_staking_price_per_confirmation);
+            }
+        }
+    }
+
+    fn is_proposal_executable(&self, proposal: &Proposal) -> bool {
+        match proposal.executable_epoch_height {
+            Some(executable_epoch_height) => self.council.is_threshold_reached(proposal) && executable_epoch_height <= env::epoch_height(),
+            None => false
+        }
+    }
+
+    fn get_proposal(&self, proposal_id: u64) -> Proposal {
+        match self.council.proposal_registry.get(&proposal_id) {
+            Some(proposal_) => proposal_,
+            None => {
+                env::panic_str("Proposal does not exist.");
+            }
+        }
+    }
+
+    fn internal_confirm_stake_distribution(&mut self) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        self.assert_epoch_phase(EpochPhase::Distributing);
+        self.assert_authorized_management_by_role(Role::Operator);
+
+        self.fund.is_distributed_on_validators_in_current_epoch = true;
+    }
+
+    fn internal_assert_invariants(&self, from_index: Option<u64>, limit: Option<u64>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        let invariant_report = self.internal_verify_invariants(from_index, limit);
+
+        if !invariant_report.discrepancy_registry.is_empty() {
+            let invariant_registry: Vec<String> = invariant_report.discrepancy_registry
+                .iter()
+                .map(|discrepancy| format!("{:?}", discrepancy.invariant))
+                .collect();
+
+            env::panic_str(format!("Invariants are broken: {}.", invariant_registry.join(", ")).as_str());
+        }
+    }
+
+    fn internal_ft_transfer(&mut self, receiver_account_id: AccountId, token_amount: Balance) -> Promise {
+        self.assert_operation_is_not_paused(PausableOperation::FtTransfer);
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        Self::assert_natural_deposit();
+
+        if token_amount == 0 {
+            env::panic_str("Insufficient token amount.");
+        }
+
+        let mut refundable_near_amount = env::attached_deposit();
+
+        let predecessor_account_id = env::predecessor_account_id();
+        let mut predecessor_account_balance = match self.fungible_token.account_registry.get(&predecessor_account_id) {
+            Some(account_balance) => account_balance,
+            None => {
+                env::panic_str("Token account is not registered yet.");
+            }
+        };
+
+        let mut receiver_account_balance = match self.fungible_token.account_registry.get(&receiver_account_id) {
+            Some(account_balance) => account_balance,
+            None => {
+                env::panic_str("Token account is not registered yet.");
+            }
+        };
+
+        if predecessor_account_balance.token_amount < token_amount {
+            env::panic_str("Token amount exceeded the available token balance.");
+        }
+
+        predecessor_account_balance.token_amount -= token_amount;
+
+        if let Some(investor_investment) = self.validating.investor_investment_registry.get(&predecessor_account_id) {
+            if (self.convert_token_amount_to_near_amount(predecessor_account_balance.token_amount) + predecessor_account_balance.investment_near_amount)
+                < investor_investment.staked_balance {
+                env::panic_str("Token amount exceeded the available to transfer token amount.");
+            }
+        }
+
+        receiver_account_balance.token_amount += token_amount;
+
+        if predecessor_account_balance.token_amount > 0
+            || self.is_protected_account(&predecessor_account_id) {
+            self.fungible_token.account_registry.insert(&predecessor_account_id, &predecessor_account_balance);
+        } else {
+            self.fungible_token.account_registry.remove(&predecessor_account_id);
+            self.fungible_token.accounts_quantity -= 1;
+
+            receiver_account_balance.classic_near_amount += predecessor_account_balance.classic_near_amount;
+
+            refundable_near_amount += Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
+        }
+        self.fungible_token.account_registry.insert(&receiver_account_id, &receiver_account_balance);
+
+        Promise::new(predecessor_account_id)
+            .transfer(refundable_near_amount)
+    }
+
+    fn internal_nft_transfer(&mut self, receiver_account_id: AccountId, token_id: TokenId, approval_id: Option<u64>, memo: Option<String>) {
+        self.assert_gas_is_enough(NUMBER_OF_TGAS_FOR_EXECUTION);
+        assert_one_yocto();
+
+        if approval_id.is_some() {
+            env::panic_str("Approvals are not supported.");
+        }
+
+        let delayed_withdrawal_id = Self::parse_delayed_withdrawal_id(&token_id);
+
+        let predecessor_account_id = env::predecessor_account_id();
+
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry.get(&delayed_withdrawal_id) {
+            Some(owner_id) => {
+                if owner_id != predecessor_account_id {
+                    env::panic_str("Delayed withdrawal can be transferred only by the owner.");
+                }
+            }
+            None => {
+                env::panic_str("Delayed withdrawal is not registered.");
+            }
+        }
+        if receiver_account_id == predecessor_account_id {
+            env::panic_str("The sender and receiver accounts can not be the same.");
+        }
+        if receiver_account_id == env::current_account_id() {
+            env::panic_str("Receiver account can not be the pool account.");
+        }
+
+        let mut sender_account_delayed_withdrawal = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&predecessor_account_id) {
+            Some(account_delayed_withdrawal_) => account_delayed_withdrawal_,
+            None => {
+                env::panic_str("Nonexecutable code. Object must exist.");
+            }
+        };
+        let delayed_withdrawal = match sender_account_delayed_withdrawal.delayed_withdrawal_registry.remove(&delayed_withdrawal_id) {
+            Some(delayed_withdrawal_) => delayed_withdrawal_,
+            None => {
+                env::panic_str("Nonexecutable code. Object must exist.");
+            }
+        };
+        sender_account_delayed_withdrawal.near_amount -= delayed_withdrawal.near_amount;
+        let is_sender_account_delayed_withdrawal_empty = sender_account_delayed_withdrawal.delayed_withdrawal_registry.is_empty();
+
+        // The storage staking paid by the sender for the emptied object covers the new receiver object.
+        let (mut receiver_account_delayed_withdrawal, released_storage_staking_price) = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&receiver_account_id) {
+            Some(account_delayed_withdrawal_) => {
+                let released_storage_staking_price_ = if is_sender_account_delayed_withdrawal_empty {
+                    sender_account_delayed_withdrawal.storage_staking_price
+                } else {
+                    0
+                };
+
+                (account_delayed_withdrawal_, released_storage_staking_price_)
+            }
+            None => {
+                if !is_sender_account_delayed_withdrawal_empty {
+                    env::panic_str("Account without delayed withdrawals can receive only the last delayed withdrawal of the sender.");
+                }
+
+                (AccountDelayedWithdrawal::new(receiver_account_id.clone(), sender_account_delayed_withdrawal.storage_staking_price), 0)
+            }
+        };
+        if receiver_account_delayed_withdrawal.delayed_withdrawal_registry.len() >= MAXIMUM_QUANTITY_OF_DELAYED_WITHDRAWALS_PER_ACCOUNT {
+            env::panic_str("Too many delayed withdrawals on the receiver account.");
+        }
+        receiver_account_delayed_withdrawal.near_amount += delayed_withdrawal.near_amount;
+        receiver_account_delayed_withdrawal.delayed_withdrawal_registry.insert(&delayed_withdrawal_id, &delayed_withdrawal);
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(&receiver_account_id, &receiver_account_delayed_withdrawal);
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry.insert(&delayed_withdrawal_id, &receiver_account_id);
+
+        if is_sender_account_delayed_withdrawal_empty {
+            self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.remove(&predecessor_account_id);
+        } else {
+            self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(&predecessor_account_id, &sender_account_delayed_withdrawal);
+        }
+
+        NftTransfer {
+            old_owner_id: &predecessor_account_id,
+            new_owner_id: &receiver_account_id,
+            token_ids: &[token_id.as_str()],
+            authorized_id: None,
+            memo: memo.as_deref()
+        }.emit();
+
+        if released_storage_staking_price > 0 {
+            Promise::new(predecessor_account_id)
+                .transfer(released_storage_staking_price);
+        }
+    }
+
+    fn internal_nft_token(&self, token_id: TokenId) -> Option<Token> {
+        let delayed_withdrawal_id = match token_id.parse::<u64>() {
+            Ok(delayed_withdrawal_id_) => delayed_withdrawal_id_,
+            Err(_) => {
+                return None;
+            }
+        };
+
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry
+            .get(&delayed_withdrawal_id)
+            .map(|owner_id| self.get_delayed_withdrawal_token(delayed_withdrawal_id, owner_id))
+    }
+
+    fn internal_nft_tokens(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<Token> {
+        let from_index_: u128 = from_index.map(|from_index__| from_index__.into()).unwrap_or(0);
+
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry
+            .iter()
+            .skip(from_index_ as usize)
+            .take(limit.unwrap_or(u64::MAX) as usize)
+            .map(|(delayed_withdrawal_id, owner_id)| self.get_delayed_withdrawal_token(delayed_withdrawal_id, owner_id))
+            .collect()
+    }
+
+    fn internal_nft_tokens_for_owner(&self, account_id: AccountId, from_index: Option<U128>, limit: Option<u64>) -> Vec<Token> {
+        let from_index_: u128 = from_index.map(|from_index__| from_index__.into()).unwrap_or(0);
+
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&account_id) {
+            Some(account_delayed_withdrawal) => {
+                account_delayed_withdrawal.delayed_withdrawal_registry
+                    .iter()
+                    .skip(from_index_ as usize)
+                    .take(limit.unwrap_or(u64::MAX) as usize)
+                    .map(|(delayed_withdrawal_id, delayed_withdrawal)| self.convert_delayed_withdrawal_to_token(delayed_withdrawal_id, account_id.clone(), delayed_withdrawal))
+                    .collect()
+            }
+            None => vec![]
+        }
+    }
+
+    pub fn internal_get_delayed_withdrawal_details(&self, account_id: AccountId) -> Option<DelayedWithdrawalDetails> {
+        let account_delayed_withdrawal = self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&account_id);
+
+        let legacy_delayed_withdrawal = self.get_legacy_delayed_withdrawal(&account_id);
+
+        if account_delayed_withdrawal.is_none() && legacy_delayed_withdrawal.is_none() {
+            return None;
+        }
+
+        let current_epoch_height = env::epoch_height();
+
+        let mut delayed_withdrawal_details = match account_delayed_withdrawal {
+            Some(account_delayed_withdrawal) => {
+                DelayedWithdrawalDetails {
+                    epoch_quantity_to_take_delayed_withdrawal: account_delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(current_epoch_height, &self.config),
+                    near_amount: account_delayed_withdrawal.near_amount.into(),
+                    matured_near_amount: account_delayed_withdrawal.get_matured_near_amount(current_epoch_height, &self.config).into()
+                }
+            }
+            None => {
+                DelayedWithdrawalDetails {
+                    epoch_quantity_to_take_delayed_withdrawal: u64::MAX,
+                    near_amount: 0.into(),
+                    matured_near_amount: 0.into()
+                }
+            }
+        };
+
+        if let Some(legacy_delayed_withdrawal_) = legacy_delayed_withdrawal {
+            let epoch_quantity_to_take_delayed_withdrawal = legacy_delayed_withdrawal_.get_epoch_quantity_to_take_delayed_withdrawal(current_epoch_height, &self.config);
+
+            delayed_withdrawal_details.epoch_quantity_to_take_delayed_withdrawal =
+                delayed_withdrawal_details.epoch_quantity_to_take_delayed_withdrawal.min(epoch_quantity_to_take_delayed_withdrawal);
+            delayed_withdrawal_details.near_amount = (delayed_withdrawal_details.near_amount.0 + legacy_delayed_withdrawal_.near_amount).into();
+            if epoch_quantity_to_take_delayed_withdrawal == 0 {
+                delayed_withdrawal_details.matured_near_amount = (delayed_withdrawal_details.matured_near_amount.0 + legacy_delayed_withdrawal_.near_amount).into();
+            }
+        }
+
+        Some(delayed_withdrawal_details)
+    }
+
+    fn internal_get_delayed_withdrawals(&self, account_id: AccountId) -> Vec<DelayedWithdrawalDto> {
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&account_id) {
+            Some(account_delayed_withdrawal) => {
+                let current_epoch_height = env::epoch_height();
+
+                let mut delayed_withdrawal_registry: Vec<DelayedWithdrawalDto> = account_delayed_withdrawal.delayed_withdrawal_registry
+                    .iter()
+                    .map(
+                        |(delayed_withdrawal_id, delayed_withdrawal)| DelayedWithdrawalDto {
+                            token_id: delayed_withdrawal_id.to_string(),
+                            near_amount: delayed_withdrawal.near_amount.into(),
+                            started_epoch_height: delayed_withdrawal.started_epoch_height,
+                            unlock_epoch_height: delayed_withdrawal.started_epoch_height + self.config.epoch_quantity_for_delayed_withdrawal,
+                            epoch_quantity_to_take_delayed_withdrawal: delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(current_epoch_height, &self.config)
+                        }
+                    )
+                    .collect();
+                delayed_withdrawal_registry.sort_by_key(|delayed_withdrawal| delayed_withdrawal.started_epoch_height);
+
+                delayed_withdrawal_registry
+            }
+            None => vec![]
+        }
+    }
+
+    fn internal_get_total_token_supply(&self) -> Balance {
+        self.fungible_token.total_supply
+    }
+
+    fn internal_get_config(&self) -> ConfigDto {
+        ConfigDto {
+            epoch_quantity_for_delayed_withdrawal: self.config.epoch_quantity_for_delayed_withdrawal,
+            epoch_quantity_for_validator_unstake: self.config.epoch_quantity_for_validator_unstake,
+            minimum_number_of_tgas: self.config.minimum_number_of_tgas,
+            minimum_deposit_amount: self.config.minimum_deposit_amount.into()
+        }
+    }
+
+    fn internal_get_pause(&self) -> PauseDto {
+        PauseDto {
+            is_deposit_paused: self.pause.is_deposit_paused,
+            is_deposit_on_validator_paused: self.pause.is_deposit_on_validator_paused,
+            is_instant_withdraw_paused: self.pause.is_instant_withdraw_paused,
+            is_delayed_withdraw_paused: self.pause.is_delayed_withdraw_paused,
+            is_take_delayed_withdrawal_paused: self.pause.is_take_delayed_withdrawal_paused,
+            is_ft_transfer_paused: self.pause.is_ft_transfer_paused,
+            is_validator_operations_paused: self.pause.is_validator_operations_paused,
+            is_liquidity_pool_paused: self.pause.is_liquidity_pool_paused
+        }
+    }
+
+    fn internal_get_account_registry(&self) -> AccountRegistryDto {
+        AccountRegistryDto {
+            owner_id: self.account_registry.owner_id.clone(),
+            manager_id: self.account_registry.manager_id.clone(),
+            pending_owner_id: self.account_registry.pending_owner_id.clone(),
+            pending_manager_id: self.account_registry.pending_manager_id.clone(),
+            self_fee_receiver_account_id: self.account_registry.self_fee_receiver_account_id.clone(),
+            partner_fee_receiver_account_id: self.account_registry.partner_fee_receiver_account_id.clone()
+        }
+    }
+
+    fn internal_get_role_accounts(&self, role: Role, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        let account_id_registry = self.role_registry.get(role).as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len()),
+            None => account_id_registry.len()
+        };
+
+        (from_index_..to_index)
+            .filter_map(|index| account_id_registry.get(index))
+            .collect()
+    }
+
+    fn internal_has_role(&self, account_id: AccountId, role: Role) -> bool {
+        self.role_registry.get(role).contains(&account_id)
+    }
+
+    fn internal_get_council(&self) -> CouncilDto {
+        CouncilDto {
+            member_account_ids: self.council.member_account_ids.clone(),
+            threshold: self.council.threshold,
+            execution_delay_epoch_quantity: self.council.execution_delay_epoch_quantity,
+            proposals_quantity: self.council.proposal_registry.len()
+        }
+    }
+
+    fn internal_get_proposals(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ProposalDto> {
+        let mut proposal_dto_registry: Vec<ProposalDto> = vec![];
+
+        let proposal_id_registry = self.council.proposal_registry.keys_as_vector();
+
+        let proposal_registry = self.council.proposal_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), proposal_id_registry.len()),
+            None => proposal_id_registry.len()
+        };
+
+        for index in from_index_..to_index {
+            match (proposal_id_registry.get(index), proposal_registry.get(index)) {
+                (Some(proposal_id), Some(proposal)) => {
+                    proposal_dto_registry.push(
+                        ProposalDto {
+                            proposal_id,
+                            proposer_account_id: proposal.proposer_account_id,
+                            action: proposal.action,
+                            near_amount: proposal.near_amount.into(),
+                            storage_staking_price: proposal.storage_staking_price.into(),
+                            confirmation_account_ids: proposal.confirmation_registry,
+                            executable_epoch_height: proposal.executable_epoch_height
+                        }
+                    );
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        proposal_dto_registry
+    }
+
+    fn internal_get_minimum_deposit_amount(&self) -> Balance {
+        self.config.minimum_deposit_amount
+    }
+
+    pub fn internal_get_storage_staking_price(&self) -> StorageStakingPrice {
+        StorageStakingPrice {
+            per_delayed_withdrawal_fund_account_delayed_withdrawal: Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_account_delayed_withdrawal).into(),
+            per_delayed_withdrawal_fund_delayed_withdrawal: Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal).into(),
+            per_delayed_withdrawal_fund_investment_withdrawal: Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal).into(),
+            per_fungible_token_account: Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account).into(),
+            per_liquidity_pool_share: Self::calculate_storage_staking_price(self.liquidity_pool.storage_usage_per_share).into(),
+            per_council_proposal_confirmation: Self::calculate_storage_staking_price(self.council.storage_usage_per_confirmation).into(),
+            per_validating_node_validator: Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator).into(),
+            per_validating_node_investor: Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment).into(),
+            per_investor_registry_investor: Self::calculate_storage_staking_price(self.investor_registry.storage_usage_per_investor).into(),
+            per_validating_node_distribution: Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution).into(),
+            per_validating_node_redelegation: Self::calculate_storage_staking_price(self.validating.storage_usage_per_redelegation).into(),
+            per_validating_node_investment_move: Self::calculate_storage_staking_price(self.validating.storage_usage_per_investment_move).into()
+        }
+    }
+
+    pub fn internal_get_storage_staking_requested_coverage(&self, account_id: AccountId) -> StorageStakingRequestedCoverage {
+        let storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal = Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal);
+
+        let storage_staking_price_per_fungible_token_account = Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
+
+        let storage_staking_price_per_validating_node_distribution = Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
+
+        let per_method_deposit = if !self.fungible_token.account_registry.contains_key(&account_id) {
+            storage_staking_price_per_fungible_token_account
+        } else {
+            0
+        };
+
+        let per_method_delayed_withdraw = self.calculate_storage_staking_price_per_additional_delayed_withdrawal(&account_id);
+
+        let (per_method_deposit_on_validator, per_method_delayed_withdraw_from_validator): (Option<(U128, Vec<(AccountId, U128)>)>, Option<(U128, Vec<(AccountId, U128)>)>) =
+            match self.validating.investor_investment_registry.get(&account_id) {
+            Some(investor_investment) => {
+                let requested_storage_staking_price_per_fungible_token_account = if !self.fungible_token.account_registry.contains_key(&account_id) {
+                    storage_staking_price_per_fungible_token_account
+                } else {
+                    0
+                };
+
+                let requested_storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal =
+                    self.calculate_storage_staking_price_per_additional_delayed_withdrawal(&account_id);
+
+                let mut requested_storage_staking_price_per_distribution_registry: Vec<(AccountId, U128)> = vec![];
+
+                let mut requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry: Vec<(AccountId, U128)> = vec![];
+
+                for validator_account_id in self.validating.validator_registry.keys() {
+                    if !investor_investment.distribution_registry.contains_key(&validator_account_id) {
+                        requested_storage_staking_price_per_distribution_registry.push((validator_account_id, storage_staking_price_per_validating_node_distribution.into()));
+                    } else {
+                        requested_storage_staking_price_per_distribution_registry.push((validator_account_id.clone(), 0.into()));
+
+                        if !self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.contains_key(&validator_account_id) {
+                            requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry.push((validator_account_id, storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal.into()));
+                        } else {
+                            requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry.push((validator_account_id, 0.into()));
+                        }
+                    }
+                }
+
+                (
+                    Some((requested_storage_staking_price_per_fungible_token_account.into(), requested_storage_staking_price_per_distribution_registry)),
+                    Some((requested_storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal.into(), requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry))
+                )
+            }
+            None => {
+                (None, None)
+            }
+        };
+
+        StorageStakingRequestedCoverage {
+            per_method_deposit: per_method_deposit.into(),
+            per_method_deposit_on_validator,
+            per_method_delayed_withdraw: per_method_delayed_withdraw.into(),
+            per_method_delayed_withdraw_from_validator
+        }
+    }
+
+    fn internal_get_account_balance(&self, account_id: AccountId) -> AccountBalanceDto {
+        match self.fungible_token.account_registry.get(&account_id) {
+            Some(account_balance) => {
+                let common_near_balance = self.convert_token_amount_to_near_amount(account_balance.token_amount)
+                + account_balance.classic_near_amount
+                + account_balance.investment_near_amount;
+
+                match self.validating.investor_investment_registry.get(&account_id) {
+                    Some(investor_investment) => {
+                        if common_near_balance < investor_investment.staked_balance {
+                            env::panic_str("Nonexecutable code. Near balance should be greater then or equal to investment near balance.");
+                        }
+
+                        let (mut investment_near_balance_token_coverage, remainder_near_amount) = self.convert_near_amount_to_token_amount(investor_investment.staked_balance);
+
+                        if remainder_near_amount > 0 {
+                            investment_near_balance_token_coverage += 1;
+                        }
+
+                        AccountBalanceDto {
+                            base_account_balance: Some(
+                                BaseAccountBalance {
+                                    token_balance: account_balance.token_amount.into(),
+                                    common_near_balance: common_near_balance.into(),
+                                    classic_near_balance: (common_near_balance - investor_investment.staked_balance).into(),
+                                    classic_near_balance_token_coverage: (account_balance.token_amount - investment_near_balance_token_coverage).into()
+                                }
+                            ),
+                            investment_account_balance: Some(
+                                InvestmentAccountBalance {
+                                    near_balance: investor_investment.staked_balance.into(),
+                                    near_balance_token_coverage: investment_near_balance_token_coverage.into()
+                                }
+                            )
+                        }
+                    }
+                    None => {
+                        AccountBalanceDto {
+                            base_account_balance: Some(
+                                BaseAccountBalance {
+                                    token_balance: account_balance.token_amount.into(),
+                                    common_near_balance: common_near_balance.into(),
+                                    classic_near_balance: common_near_balance.into(),
+                                    classic_near_balance_token_coverage: account_balance.token_amount.into(),
+                                }
+                            ),
+                            investment_account_balance: None
+                        }
+                    }
+                }
+            }
+            None => {
+                match self.validating.investor_investment_registry.get(&account_id) {
+                    Some(_) => {
+                        AccountBalanceDto {
+                            base_account_balance: None,
+                            investment_account_balance: Some(
+                                InvestmentAccountBalance {
+                                    near_balance: 0.into(),
+                                    near_balance_token_coverage: 0.into()
+                                }
+                            )
+                        }
+                    }
+                    None => {
+                        AccountBalanceDto {
+                            base_account_balance: None,
+                            investment_account_balance: None
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn internal_get_fund(&self) -> FundDto {
+        FundDto {
+            classic_unstaked_balance: self.fund.classic_unstaked_balance.into(),
+            classic_staked_balance: self.fund.classic_staked_balance.into(),
+            classic_liquidity_balance: self.fund.classic_liquidity_balance.into(),
+            investment_staked_balance: self.fund.investment_staked_balance.into(),
+            common_staked_balance: self.fund.get_staked_balance().into(),
+            common_balance: self.fund.get_common_balance().into(),
+            is_exchange_rate_stale: self.is_exchange_rate_stale(),
+            redelegation_registry: self.validating.redelegation_registry
+                .iter()
+                .map(
+                    |(source_validator_account_id, redelegation)| RedelegationDto {
+                        source_validator_account_id,
+                        target_validator_account_id: redelegation.target_validator_account_id,
+                        near_amount: redelegation.near_amount.into(),
+                        unstaked_near_amount: redelegation.unstaked_near_amount.into()
+                    }
+                )
+                .collect()
+        }
+    }
+
+    fn internal_get_liquidity_pool(&self) -> LiquidityPoolDto {
+        LiquidityPoolDto {
+            near_amount: self.fund.classic_liquidity_balance.into(),
+            token_amount: self.liquidity_pool.token_amount.into(),
+            delayed_withdrawal_near_amount: self.get_liquidity_pool_delayed_withdrawal_near_amount().into(),
+            common_near_amount: self.get_liquidity_pool_common_near_amount().into(),
+            total_share_amount: self.liquidity_pool.total_share_amount.into(),
+            liquidity_providers_quantity: self.liquidity_pool.liquidity_providers_quantity
+        }
+    }
+
+    fn internal_get_liquidity_provider_share(&self, account_id: AccountId) -> Option<LiquidityProviderShareDto> {
+        match self.liquidity_pool.share_registry.get(&account_id) {
+            Some(share_amount) => {
+                let near_amount = (
+                    U256::from(share_amount)
+                    * U256::from(self.get_liquidity_pool_common_near_amount())
+                    / U256::from(self.liquidity_pool.total_share_amount)
+                ).as_u128();
+
+                Some(
+                    LiquidityProviderShareDto {
+                        share_amount: share_amount.into(),
+                        near_amount: near_amount.into()
+                    }
+                )
+            }
+            None => None
+        }
+    }
+
+    fn internal_get_keeper_reward(&self) -> KeeperRewardDto {
+        KeeperRewardDto {
+            budget: self.keeper_reward.budget.into(),
+            near_amount_per_call: self.keeper_reward.near_amount_per_call.into(),
+            maximum_near_amount_per_epoch: self.keeper_reward.maximum_near_amount_per_epoch.into(),
+            paid_near_amount_in_current_epoch: self.keeper_reward.paid_near_amount_in_current_epoch.into(),
+            current_epoch_height: self.keeper_reward.current_epoch_height
+        }
+    }
+
+    fn internal_get_fee_registry(&self) -> FeeRegistry {
+        self.assert_authorized_management_by_role(Role::FeeAdmin);
+
+        self.fee_registry.clone()
+    }
+
+    fn internal_get_fee_registry_light(&self) -> FeeRegistryLight {
+        let reward_fee = match self.fee_registry.reward_fee {
+            Some(ref reward_fee_) => Some(reward_fee_.self_fee.clone()),
+            None => None
+        };
+
+        let instant_withdraw_fee = match self.fee_registry.instant_withdraw_fee {
+            Some(ref instant_withdraw_fee_) => Some(instant_withdraw_fee_.self_fee.clone()),
+            None => None
+        };
+
+        FeeRegistryLight {
+            reward_fee,
+            instant_withdraw_fee
+        }
+    }
+
+    pub fn internal_get_current_epoch_height(&self) -> EpochHeightRegistry {
+        EpochHeightRegistry {
+            pool_epoch_height: self.current_epoch_height,
+            network_epoch_height: env::epoch_height()
+        }
+    }
+
+    fn internal_get_epoch_phase(&self, from_index: Option<u64>, limit: Option<u64>) -> EpochPhaseDetails {
+        let network_epoch_height = env::epoch_height();
+
+        let epoch_phase = self.calculate_epoch_phase();
+
+        let mut validators_to_update: Vec<AccountId> = vec![];
+
+        let mut validators_to_take_unstaked_balance: Vec<AccountId> = vec![];
+
+        let mut validators_to_request_investment_withdrawal: Vec<AccountId> = vec![];
+
+        let account_id_registry = self.validating.validator_registry.keys_as_vector();
+
+        let validator_registry = self.validating.validator_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len()),
+            None => account_id_registry.len()
+        };
+
+        for index in from_index_..to_index {
+            match (account_id_registry.get(index), validator_registry.get(index)) {
+                (Some(account_id), Some(validator)) => {
+                    match epoch_phase {
+                        EpochPhase::UpdatingValidators if validator.last_update_epoch_height < network_epoch_height => {
+                            if self.config.is_right_epoch(network_epoch_height) && validator.balance.get_unstaked_balance() > 0 {
+                                validators_to_take_unstaked_balance.push(account_id.clone());
+                            }
+
+                            validators_to_update.push(account_id);
+                        }
+                        EpochPhase::UnstakingRequested
+                            if !validator.is_stake_decreasing
+                                && self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&account_id).is_some() => {
+                            validators_to_request_investment_withdrawal.push(account_id);
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        EpochPhaseDetails {
+            epoch_phase,
+            pool_epoch_height: self.current_epoch_height,
+            network_epoch_height,
+            validators_to_update,
+            validators_to_take_unstaked_balance,
+            validators_to_request_investment_withdrawal,
+            needed_to_request_classic_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount.into(),
+            needed_to_request_investment_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount.into(),
+            classic_unstaked_balance: self.fund.classic_unstaked_balance.into()
+        }
+    }
+
+    pub fn internal_is_stake_distributed(&self) -> bool {
+        self.fund.is_distributed_on_validators_in_current_epoch
+    }
+
+    fn internal_get_investors(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<InvestorDto> {
+        let mut investor_dto_registry: Vec<InvestorDto> = vec![];
+
+        let account_id_registry = self.investor_registry.investor_setting_registry.keys_as_vector();
+
+        let investor_setting_registry = self.investor_registry.investor_setting_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len()),
+            None => account_id_registry.len()
+        };
+
+        for index in from_index_..to_index {
+            match (account_id_registry.get(index), investor_setting_registry.get(index)) {
+                (Some(account_id), Some(investor_setting)) => {
+                    investor_dto_registry.push(Self::convert_investor_setting_to_dto(account_id, investor_setting));
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        investor_dto_registry
+    }
+
+    fn internal_get_validator_proposals(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ValidatorProposalDto> {
+        let mut validator_proposal_dto_registry: Vec<ValidatorProposalDto> = vec![];
+
+        let account_id_registry = self.investor_registry.validator_proposal_registry.keys_as_vector();
+
+        let validator_proposal_registry = self.investor_registry.validator_proposal_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len()),
+            None => account_id_registry.len()
+        };
+
+        for index in from_index_..to_index {
+            match (account_id_registry.get(index), validator_proposal_registry.get(index)) {
+                (Some(account_id), Some(validator_proposal)) => {
+                    validator_proposal_dto_registry.push(
+                        ValidatorProposalDto {
+                            validator_account_id: account_id,
+                            investor_account_id: validator_proposal.investor_account_id,
+                            staking_contract_version: validator_proposal.staking_contract_version,
+                            near_amount: validator_proposal.near_amount.into(),
+                            storage_staking_price: validator_proposal.storage_staking_price.into()
+                        }
+                    );
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        validator_proposal_dto_registry
+    }
+
+    fn internal_get_investor(&self, account_id: AccountId) -> Option<InvestorDto> {
+        self.investor_registry.investor_setting_registry.get(&account_id).map(
+            |investor_setting| Self::convert_investor_setting_to_dto(account_id, investor_setting)
+        )
+    }
+
+    pub fn internal_get_investor_investment(&self, account_id: AccountId) -> Option<InvestorInvestmentDto> {
+        let mut distribution_registry: Vec<(AccountId, U128)> = vec![];
+
+        let mut validators_for_migration: Vec<AccountId> = vec![];
+
+        let investor_investment = match self.validating.investor_investment_registry.get(&account_id) {
+            Some(investor_investment_) => investor_investment_,
+            None => {
+                return None;
+            }
+        };
+
+        for (validator_account_id, validator) in self.validating.validator_registry.iter() {
+            if let Some(staked_balance) = investor_investment.distribution_registry.get(&validator_account_id) {
+                if validator.is_retiring {
+                    validators_for_migration.push(validator_account_id.clone());
+                }
+
+                distribution_registry.push((validator_account_id, staked_balance.into()));
+            }
+        }
+
+        let investment_move = self.validating.investment_move_registry.get(&account_id).map(
+            |investment_move_| InvestmentMoveDto {
+                epoch_quantity_to_complete: investment_move_.get_epoch_quantity_to_complete_investment_move(self.current_epoch_height, &self.config),
+                source_validator_account_id: investment_move_.source_validator_account_id,
+                target_validator_account_id: investment_move_.target_validator_account_id,
+                near_amount: investment_move_.near_amount.into()
+            }
+        );
+
+        Some(
+            InvestorInvestmentDto {
+                distribution_registry,
+                staked_balance: investor_investment.staked_balance.into(),
+                validators_for_migration,
+                investment_move
+            }
+        )
+    }
+
+    fn internal_get_validator_registry(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ValidatorDto> {
+        let mut validator_dto_registry: Vec<ValidatorDto> = vec![];
+
+        let account_id_registry = self.validating.validator_registry.keys_as_vector();
+
+        let validator_registry = self.validating.validator_registry.values_as_vector();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(from_index_.saturating_add(limit_), account_id_registry.len()),
+            None => account_id_registry.len()
+        };
+
+        for index in from_index_..to_index {
+            match (account_id_registry.get(index), validator_registry.get(index)) {
+                (Some(account_id), Some(validator)) => {
+                    validator_dto_registry.push(self.convert_validator_to_dto(account_id, validator));
+                }
+                _ => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            }
+        }
+
+        validator_dto_registry
+    }
+
+    fn internal_get_preffered_validator(&self) -> Option<ValidatorDto> {
+        if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
+            let validator = match self.validating.validator_registry.get(preffered_validator_account_id) {
+                Some(validator_) => validator_,
+                None => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            };
+
+            return Some(self.convert_validator_to_dto(preffered_validator_account_id.clone(), validator));
+        }
+
+        None
+    }
+
+    fn internal_get_validator_performance(&self, validator_account_id: AccountId) -> Option<ValidatorPerformanceDto> {
+        let validator = self.validating.validator_registry.get(&validator_account_id)?;
+
+        let epoch_reward_history = validator.performance
+            .get_epoch_reward_history()
+            .into_iter()
+            .map(
+                |epoch_reward| EpochRewardDto {
+                    epoch_height: epoch_reward.epoch_height,
+                    epoch_quantity: epoch_reward.epoch_quantity,
+                    staked_near_amount: epoch_reward.staked_near_amount.into(),
+                    rewards_near_amount: epoch_reward.rewards_near_amount.into()
+                }
+            )
+            .collect();
+
+        Some(
+            ValidatorPerformanceDto {
+                epoch_reward_history,
+                trailing_apy: validator.performance.calculate_trailing_apy(),
+                missed_epochs_quantity: validator.performance.get_missed_epochs_quantity(),
+                score: validator.performance.calculate_score(),
+                minimum_validator_score: self.validating.minimum_validator_score
+            }
+        )
+    }
+
+    fn internal_get_aggregated(&self) -> Aggregated {
+        Aggregated {
+            unstaked_balance: self.fund.classic_unstaked_balance.into(),
+            staked_balance: self.fund.get_staked_balance().into(),
+            token_total_supply: self.fungible_token.total_supply.into(),
+            token_accounts_quantity: self.fungible_token.accounts_quantity,
+            total_rewards_from_validators_near_amount: self.reward.total_rewards_from_validators_near_amount.into(),
+            reward_fee: self.get_fee_registry_light().reward_fee,
+            is_exchange_rate_stale: self.is_exchange_rate_stale()
+        }
+    }
+
+    fn internal_get_requested_to_withdrawal_fund(&self) -> RequestedToWithdrawalFund {
+        let mut investment_withdrawal_registry: Vec<(AccountId, U128)> = vec![];
+
+        for validator_account_id in self.validating.validator_registry.keys() {
+            if let Some(investment_withdrawal) = self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
+                investment_withdrawal_registry.push((validator_account_id, investment_withdrawal.near_amount.into()))
+            }
+        }
+
+        RequestedToWithdrawalFund {
+            classic_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount.into(),
+            investment_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount.into(),
+            investment_withdrawal_registry
+        }
+    }
+
+    pub fn internal_get_full(&self) -> Full {
+        Full {
+            storage_staking_price: self.internal_get_storage_staking_price(),
+            fund: self.internal_get_fund(),
+            total_token_supply: self.internal_get_total_token_supply().into(),
+            requested_to_withdrawal_fund: self.internal_get_requested_to_withdrawal_fund(),
+            fee_registry_light: self.internal_get_fee_registry_light(),
+            minimum_deposit_amount: self.get_minimum_deposit_amount()
+        }
+    }
+
+    /// The page goes through the validators and then through the investors listed in the investor registry, so one
+    /// page is limited by the quantity of the entries. The validator sums are compared with the fund only on the page
+    /// containing all validators, the investor sums only on the page containing all listed investors, otherwise the sums
+    /// of the pages should be added up. Investors that are not listed in the investor registry are not verified.
+    /// The fungible token registry is a LookupMap and is not iterable, so the accounts quantity is not verified.
+    fn internal_verify_invariants(&self, from_index: Option<u64>, limit: Option<u64>) -> InvariantReport {
+        let mut discrepancy_registry: Vec<Discrepancy> = vec![];
+
+        let validator_registry = self.validating.validator_registry.values_as_vector();
+        let validator_account_id_registry = self.validating.validator_registry.keys_as_vector();
+        let investor_account_id_registry = self.investor_registry.investor_setting_registry.keys_as_vector();
+
+        let validators_quantity = validator_registry.len();
+        let entries_quantity = validators_quantity + investor_account_id_registry.len();
+
+        let from_index_ = from_index.unwrap_or(0);
+
+        let to_index = match limit {
+            Some(limit_) => std::cmp::min(limit_, MAXIMUM_QUANTITY_OF_ENTRIES_IN_INVARIANT_VERIFICATION),
+            None => MAXIMUM_QUANTITY_OF_ENTRIES_IN_INVARIANT_VERIFICATION
+        };
+        let to_index = std::cmp::min(from_index_.saturating_add(to_index), entries_quantity);
+
+        if validators_quantity != self.validating.validators_quantity {
+            discrepancy_registry.push(
+                Discrepancy {
+                    invariant: Invariant::ValidatorsQuantity,
+                    account_id: None,
+                    expected: (self.validating.validators_quantity as Balance).into(),
+                    actual: (validators_quantity as Balance).into()
+                }
+            );
+        }
+
+        for fee_receiver_account_id in [
+            &self.account_registry.self_fee_receiver_account_id,
+            &self.account_registry.partner_fee_receiver_account_id
+        ] {
+            if self.fungible_token.account_registry.get(fee_receiver_account_id).is_none() {
+                discrepancy_registry.push(
+                    Discrepancy {
+                        invariant: Invariant::FeeReceiverRegistration,
+                        account_id: Some(fee_receiver_account_id.clone()),
+                        expected: 1.into(),
+                        actual: 0.into()
+                    }
+                );
+            }
+        }
+
+        let required_near_amount = self.fund.classic_unstaked_balance
+            + self.fund.classic_liquidity_balance
+            + self.fund.delayed_withdrawn_fund.balance
+            + self.validating.withdrawn_moving_investment_near_amount
+            + self.keeper_reward.budget
+            + Self::calculate_storage_staking_price(env::storage_usage());
+        let near_amount = env::account_balance();
+        if near_amount < required_near_amount {
+            discrepancy_registry.push(
+                Discrepancy {
+                    invariant: Invariant::NearBalance,
+                    account_id: None,
+                    expected: required_near_amount.into(),
+                    actual: near_amount.into()
+                }
+            );
+        }
+
+        let mut validators_classic_near_amount: Balance = 0;
+        let mut validators_investment_near_amount: Balance = 0;
+        let mut is_stake_decreasing = false;
+
+        for index in from_index_..std::cmp::min(to_index, validators_quantity) {
+            let validator = match validator_registry.get(index) {
+                Some(validator_) => validator_,
+                None => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
+                }
+            };
+
+            validators_classic_near_amount += validator.balance.classic_near_amount + validator.balance.requested_to_restaking_near_amount;
+            validators_investment_near_amount += validator.balance.investment_near_amount;
+            is_stake_decreasing = is_stake_decreasing || validator.is_stake_decreasing;
+        }
+
+        // The sums are complete only if the page contains all validators.
+        if from_index_ == 0 && to_index >= validators_quantity {
+            let expected_classic_near_amount = self.fund.classic_staked_balance
+                + self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount
+                + self.fund.delayed_withdrawn_fund.queued_to_request_classic_near_amount
+                + self.reward.previous_epoch_rewards_from_validators_near_amount;
+            if validators_classic_near_amount != expected_classic_near_amount {
+                discrepancy_registry.push(
+                    Discrepancy {
+                        invariant: Invariant::ClassicStakedBalance,
+                        account_id: None,
+                        expected: expected_classic_near_amount.into(),
+                        actual: validators_classic_near_amount.into()
+                    }
+                );
+            }
+
+            let expected_investment_near_amount = self.fund.investment_staked_balance
+                + self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount;
+            let investment_near_amount = validators_investment_near_amount + self.validating.moving_investment_near_amount;
+            if investment_near_amount != expected_investment_near_amount {
+                discrepancy_registry.push(
+                    Discrepancy {
+                        invariant: Invariant::InvestmentStakedBalance,
+                        account_id: None,
+                        expected: expected_investment_near_amount.into(),
+                        actual: investment_near_amount.into()
+                    }
+                );
+            }
+        }
+
+        let mut investors_staked_balance: Balance = 0;
+
+        for index in std::cmp::max(from_index_, validators_quantity)..to_index {
+            let investor_account_id = match investor_account_id_registry.get(index - validators_quantity) {
+                Some(investor_account_id_) => investor_account_id_,
+                None => {
+                    env::panic_str("Nonexecutable code. Object must exist.");
                 }
-            )
+            };
+
+            let investor_investment = match self.validating.investor_investment_registry.get(&investor_account_id) {
+                Some(investor_investment_) => investor_investment_,
+                None => {
+                    discrepancy_registry.push(
+                        Discrepancy {
+                            invariant: Invariant::InvestorRegistration,
+                            account_id: Some(investor_account_id),
+                            expected: 1.into(),
+                            actual: 0.into()
+                        }
+                    );
+
+                    continue;
+                }
+            };
+            investors_staked_balance += investor_investment.staked_balance;
+
+            // The distribution registry is not iterable, so the search stops as soon as all distributions are found.
+            let mut distributions_quantity: u64 = 0;
+            let mut staked_balance: Balance = 0;
+            for validator_account_id in validator_account_id_registry.iter() {
+                if distributions_quantity == investor_investment.distributions_quantity {
+                    break;
+                }
+
+                if let Some(distribution_near_amount) = investor_investment.distribution_registry.get(&validator_account_id) {
+                    distributions_quantity += 1;
+                    staked_balance += distribution_near_amount;
+                }
+            }
+
+            if distributions_quantity != investor_investment.distributions_quantity {
+                discrepancy_registry.push(
+                    Discrepancy {
+                        invariant: Invariant::InvestorDistributionsQuantity,
+                        account_id: Some(investor_account_id.clone()),
+                        expected: (investor_investment.distributions_quantity as Balance).into(),
+                        actual: (distributions_quantity as Balance).into()
+                    }
+                );
+            }
+
+            if let Some(investment_move) = self.validating.investment_move_registry.get(&investor_account_id) {
+                staked_balance += investment_move.near_amount;
+            }
+            if staked_balance != investor_investment.staked_balance {
+                discrepancy_registry.push(
+                    Discrepancy {
+                        invariant: Invariant::InvestorStakedBalance,
+                        account_id: Some(investor_account_id),
+                        expected: investor_investment.staked_balance.into(),
+                        actual: staked_balance.into()
+                    }
+                );
+            }
+        }
+
+        // The sum is complete only if the page contains all listed investors.
+        if from_index_ <= validators_quantity
+            && to_index == entries_quantity
+            && investors_staked_balance != self.fund.investment_staked_balance {
+            discrepancy_registry.push(
+                Discrepancy {
+                    invariant: Invariant::InvestorsStakedBalance,
+                    account_id: None,
+                    expected: self.fund.investment_staked_balance.into(),
+                    actual: investors_staked_balance.into()
+                }
+            );
+        }
+
+        let next_index = if to_index < entries_quantity {
+            Some(to_index)
         } else {
             None
         };
+
+        InvariantReport {
+            discrepancy_registry,
+            validators_classic_near_amount: validators_classic_near_amount.into(),
+            validators_investment_near_amount: validators_investment_near_amount.into(),
+            investors_staked_balance: investors_staked_balance.into(),
+            next_index,
+            is_stake_decreasing
+        }
     }
 
-    fn internal_change_instant_withdraw_fee(&mut self, instant_withdraw_fee_self: Option<Fee>, instant_withdraw_fee_partner: Option<Fee>) {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
+    pub fn internal_get_full_for_account(&self, account_id: AccountId) -> FullForAccount {
+        FullForAccount {
+            full: self.internal_get_full(),
+            account_balance: self.internal_get_account_balance(account_id.clone()),
+            delayed_withdrawal_details: self.internal_get_delayed_withdrawal_details(account_id.clone()),
+            investor_investment: self.get_investor_investment(account_id.clone()),
+            storage_staking_requested_coverage: self.get_storage_staking_requested_coverage(account_id)
+        }
+    }
 
-        if instant_withdraw_fee_self.is_none() && instant_withdraw_fee_partner.is_some() {
-            env::panic_str("Instant withdraw fees are not valid.");
+    fn internal_ft_total_supply(&self) -> Balance {
+        self.fungible_token.total_supply
+    }
+
+    fn internal_ft_balance_of(&self, account_id: AccountId) -> Balance {
+        match self.fungible_token.account_registry.get(&account_id) {
+            Some(account_balance) => account_balance.token_amount,
+            None => 0
         }
-        self.fee_registry.instant_withdraw_fee = if let Some(instant_withdraw_fee_self_) = instant_withdraw_fee_self {
-            instant_withdraw_fee_self_.assert_valid();
+    }
 
-            if let Some(ref instant_withdraw_fee_partner) = instant_withdraw_fee_partner {
-                instant_withdraw_fee_partner.assert_valid();
+    fn convert_near_amount_to_token_amount(&self, near_amount: Balance) -> (Balance, Balance) {
+        let common_balance = self.fund.get_common_balance();
+
+        if common_balance == 0 || near_amount == 0 || self.fungible_token.total_supply == 0 {
+            return (near_amount, 0);
+        }
+
+        let token_amount = (
+            U256::from(near_amount)
+            * U256::from(self.fungible_token.total_supply)
+            / U256::from(common_balance)
+        ).as_u128();
+
+        let remainder_near_amount = near_amount - self.convert_token_amount_to_near_amount(token_amount);
+
+
+        (token_amount, remainder_near_amount)
+    }
+
+    fn convert_token_amount_to_near_amount(&self, token_amount: Balance) -> Balance {
+        if self.fungible_token.total_supply == 0 || token_amount == 0 {
+            return token_amount
+        }
+
+        (
+            U256::from(token_amount)
+            * U256::from(self.fund.get_common_balance())
+            / U256::from(self.fungible_token.total_supply)
+        ).as_u128()
+    }
+
+    /// Applies the validator total balance received from the validator to the validator state.
+    /// Returns false if the validator is already updated in the current epoch.
+    fn process_validator_update(&mut self, validator_account_id: AccountId, current_epoch_height: EpochHeight, data: &[u8]) -> bool {
+        let new_balance: u128 = match near_sdk::serde_json::from_slice::<U128>(data) {
+            Ok(new_balance_) => new_balance_.into(),
+            Err(_) => {
+                env::panic_str("Nonexecutable code. It should be valid JSON object.");
             }
+        };
 
-            Some (
-                SharedFee {
-                    self_fee: instant_withdraw_fee_self_,
-                    partner_fee: instant_withdraw_fee_partner
+        let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+            Some(validator_) => validator_,
+            None => {
+                env::panic_str("Nonexecutable code. Object must exist.");
+            }
+        };
+        if validator.last_update_epoch_height >= current_epoch_height {
+            return false;
+        }
+
+        let staking_rewards_near_amount = new_balance - validator.balance.get_balance();
+
+        let staked_near_amount = validator.balance.classic_near_amount + validator.balance.investment_near_amount;
+        if staked_near_amount > 0 {
+            validator.performance.add_epoch_reward(
+                EpochReward {
+                    epoch_height: current_epoch_height,
+                    epoch_quantity: current_epoch_height - validator.last_update_epoch_height,
+                    staked_near_amount,
+                    rewards_near_amount: staking_rewards_near_amount
                 }
-            )
+            );
+        }
+
+        validator.last_update_epoch_height = current_epoch_height;
+        validator.balance.classic_near_amount += staking_rewards_near_amount;
+
+        self.validating.validator_registry.insert(&validator_account_id, &validator);
+        self.validating.quantity_of_validators_updated_in_current_epoch += 1;
+
+        self.reward.previous_epoch_rewards_from_validators_near_amount += staking_rewards_near_amount;
+
+        let mut requested_to_restaking_near_amount: Balance = 0;
+        if self.config.is_right_epoch(current_epoch_height) {
+            if validator.is_retiring {
+                requested_to_restaking_near_amount = validator.balance.classic_near_amount;
+            } else if let Some(redelegation) = self.validating.redelegation_registry.get(&validator_account_id) {
+                if redelegation.unstaked_near_amount == 0 {
+                    requested_to_restaking_near_amount = redelegation.near_amount.min(validator.balance.classic_near_amount);
+                }
+            }
+        }
+        if requested_to_restaking_near_amount > 0 {
+            match validator.staking_contract_version {
+                StakingContractVersion::Core => {
+                    classic_validator::ext(validator_account_id.clone())
+                        .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE)
+                        .unstake(requested_to_restaking_near_amount.into())
+                        .then(
+                            Self::ext(env::current_account_id())
+                                .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DECREASE_VALIDATOR_STAKE_FOR_RESTAKING_CALLBACK)
+                                .decrease_validator_stake_for_restaking_callback(
+                                    validator_account_id.clone(),
+                                    requested_to_restaking_near_amount
+                                )
+                        );
+                }
+            }
+        }
+
+        let current_account_id_log = env::current_account_id();
+        env::log_str(
+            format!(
+                "
+                Updating validator @{} from {} epoch to {} epoch.
+                Old @{} classic Near amount on validator is {} yoctoNear.
+                Old @{} investment Near amount on validator is {} yoctoNear.
+                Old @{} unstaked near amount from validator is {} yoctoNear.
+                Received on validator Near amount is {} yoctoNear.
+                New @{} classic Near amount on validator is {} yoctoNear.
+                New @{} investment Near amount on validator is {} yoctoNear.
+                New @{} unstaked near amount from validator is {} yoctoNear.
+                ",
+                validator_account_id,
+                current_epoch_height - 1,
+                current_epoch_height,
+                current_account_id_log,
+                validator.balance.classic_near_amount - staking_rewards_near_amount,
+                current_account_id_log,
+                validator.balance.investment_near_amount,
+                current_account_id_log,
+                validator.balance.requested_to_withdrawal_near_amount,
+                staking_rewards_near_amount,
+                current_account_id_log,
+                validator.balance.classic_near_amount,
+                current_account_id_log,
+                validator.balance.investment_near_amount,
+                current_account_id_log,
+                validator.balance.requested_to_withdrawal_near_amount
+            ).as_str()
+        );
+
+        true
+    }
+
+    /// Removes the investor distributions with zero staked balance on the listed validators and returns the storage staking
+    /// price paid by the investor for them. The distributions are checked directly, so the validators that are not registered
+    /// anymore can be listed.
+    fn remove_empty_distributions(&self, investor_investment: &mut InvestorInvestment, validator_account_ids: &[AccountId]) -> Balance {
+        if validator_account_ids.len() as u64 > MAXIMUM_QUANTITY_OF_VALIDATORS_IN_DISTRIBUTION_LIST {
+            env::panic_str("Too many validators in the list.");
+        }
+        for (index, validator_account_id) in validator_account_ids.iter().enumerate() {
+            if validator_account_ids[..index].contains(validator_account_id) {
+                env::panic_str("Listed validators must be unique.");
+            }
+        }
+
+        let mut empty_distributions_quantity: u64 = 0;
+
+        for validator_account_id in validator_account_ids.iter() {
+            if investor_investment.distribution_registry.get(validator_account_id) == Some(0) {
+                investor_investment.distribution_registry.remove(validator_account_id);
+                investor_investment.distributions_quantity -= 1;
+
+                empty_distributions_quantity += 1;
+            }
+        }
+
+        Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution) * empty_distributions_quantity as Balance
+    }
+
+    /// Fee receivers are protected from the removal from the fungible token registry, because the fees are credited to them
+    /// without the storage staking.
+    fn is_protected_account(&self, account_id: &AccountId) -> bool {
+        *account_id == self.account_registry.self_fee_receiver_account_id
+            || *account_id == self.account_registry.partner_fee_receiver_account_id
+    }
+
+    /// Storage staking price for the registration of the fee receivers that are not registered yet.
+    fn calculate_storage_staking_price_per_additional_fee_receivers(
+        &self,
+        self_fee_receiver_account_id: &AccountId,
+        partner_fee_receiver_account_id: &AccountId
+    ) -> Balance {
+        let additional_accounts_quantity = [self_fee_receiver_account_id, partner_fee_receiver_account_id]
+            .iter()
+            .filter(|account_id| self.fungible_token.account_registry.get(account_id).is_none())
+            .count();
+
+        Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account) * additional_accounts_quantity as Balance
+    }
+
+    /// Storage staking price for the additional delayed withdrawal of the account.
+    fn calculate_storage_staking_price_per_additional_delayed_withdrawal(&self, account_id: &AccountId) -> Balance {
+        let storage_staking_price_per_additional_delayed_withdrawal =
+            Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal);
+
+        if self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.contains_key(account_id) {
+            storage_staking_price_per_additional_delayed_withdrawal
         } else {
-            None
+            Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_account_delayed_withdrawal)
+                + storage_staking_price_per_additional_delayed_withdrawal
+        }
+    }
+
+    /// Creates the delayed withdrawal and the non-fungible token representing it.
+    fn add_delayed_withdrawal(&mut self, account_id: &AccountId, near_amount: Balance, started_epoch_height: EpochHeight) -> AccountDelayedWithdrawal {
+        let mut account_delayed_withdrawal = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(account_id) {
+            Some(account_delayed_withdrawal_) => account_delayed_withdrawal_,
+            None => {
+                AccountDelayedWithdrawal::new(
+                    account_id.clone(),
+                    Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_account_delayed_withdrawal)
+                )
+            }
         };
+        if account_delayed_withdrawal.delayed_withdrawal_registry.len() >= MAXIMUM_QUANTITY_OF_DELAYED_WITHDRAWALS_PER_ACCOUNT {
+            env::panic_str("Too many delayed withdrawals. Take the available delayed withdrawals first.");
+        }
+
+        let delayed_withdrawal_id = self.fund.delayed_withdrawn_fund.next_delayed_withdrawal_id;
+        self.fund.delayed_withdrawn_fund.next_delayed_withdrawal_id += 1;
+
+        account_delayed_withdrawal.delayed_withdrawal_registry.insert(
+            &delayed_withdrawal_id,
+            &DelayedWithdrawal {
+                near_amount,
+                started_epoch_height
+            }
+        );
+        account_delayed_withdrawal.near_amount += near_amount;
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(account_id, &account_delayed_withdrawal);
+        self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry.insert(&delayed_withdrawal_id, account_id);
+
+        NftMint {
+            owner_id: account_id,
+            token_ids: &[delayed_withdrawal_id.to_string().as_str()],
+            memo: None
+        }.emit();
+
+        account_delayed_withdrawal
     }
 
-    fn internal_confirm_stake_distribution(&mut self) {
-        Self::assert_gas_is_enough();
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
+    fn convert_delayed_withdrawal_to_token(&self, delayed_withdrawal_id: u64, owner_id: AccountId, delayed_withdrawal: DelayedWithdrawal) -> Token {
+        let unlock_epoch_height = delayed_withdrawal.started_epoch_height + self.config.epoch_quantity_for_delayed_withdrawal;
+
+        Token {
+            token_id: delayed_withdrawal_id.to_string(),
+            owner_id,
+            metadata: Some(
+                TokenMetadata {
+                    title: Some(format!("Delayed withdrawal #{}", delayed_withdrawal_id)),
+                    description: Some(
+                        format!(
+                            "Claim for {} yoctoNear available for taking from {} epoch.",
+                            delayed_withdrawal.near_amount,
+                            unlock_epoch_height
+                        )
+                    ),
+                    media: None,
+                    media_hash: None,
+                    copies: Some(1),
+                    issued_at: None,
+                    expires_at: None,
+                    starts_at: None,
+                    updated_at: None,
+                    extra: Some(
+                        format!(
+                            "{{\"near_amount\":\"{}\",\"started_epoch_height\":{},\"unlock_epoch_height\":{}}}",
+                            delayed_withdrawal.near_amount,
+                            delayed_withdrawal.started_epoch_height,
+                            unlock_epoch_height
+                        )
+                    ),
+                    reference: None,
+                    reference_hash: None
+                }
+            ),
+            approved_account_ids: None
+        }
+    }
+
+    fn get_delayed_withdrawal_token(&self, delayed_withdrawal_id: u64, owner_id: AccountId) -> Token {
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&owner_id) {
+            Some(account_delayed_withdrawal) => {
+                match account_delayed_withdrawal.delayed_withdrawal_registry.get(&delayed_withdrawal_id) {
+                    Some(delayed_withdrawal) => self.convert_delayed_withdrawal_to_token(delayed_withdrawal_id, owner_id, delayed_withdrawal),
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                }
+            }
+            None => {
+                env::panic_str("Nonexecutable code. Object must exist.");
+            }
+        }
+    }
+
+    fn parse_delayed_withdrawal_id(token_id: &TokenId) -> u64 {
+        match token_id.parse::<u64>() {
+            Ok(delayed_withdrawal_id) => delayed_withdrawal_id,
+            Err(_) => {
+                env::panic_str("Token id is not valid.");
+            }
+        }
+    }
+
+    /// Exchanges tokens to Near from the liquidity pool. The whole token amount, including the fee, goes to the liquidity pool,
+    /// so the fee is earned by the liquidity providers.
+    fn instant_withdraw_from_liquidity_pool(
+        &mut self,
+        predecessor_account_id: AccountId,
+        account_balance: AccountBalance,
+        token_amount: Balance,
+        mut near_amount: Balance,
+        token_balance_log: Balance,
+        instant_withdraw_fee_self_log: Option<Fee>
+    ) -> Promise {
+        self.fund.classic_liquidity_balance -= near_amount;
+
+        self.liquidity_pool.token_amount += token_amount;
+
+        let released_storage_staking_price_per_additional_account_log = if account_balance.token_amount > 0
+            || account_balance.classic_near_amount > 0
+            || self.is_protected_account(&predecessor_account_id) {
+            self.fungible_token.account_registry.insert(&predecessor_account_id, &account_balance);
+
+            0
+        } else {
+            self.fungible_token.account_registry.remove(&predecessor_account_id);
+            self.fungible_token.accounts_quantity -= 1;
+
+            let storage_staking_price_per_additional_account = Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
+
+            near_amount += storage_staking_price_per_additional_account;
+
+            storage_staking_price_per_additional_account
+        };
+
+        let attached_deposit = env::attached_deposit();
+
+        near_amount += attached_deposit;
+
+        let current_account_id_log = env::current_account_id();
+        env::log_str(
+            format!(
+                "
+                Instant withdrawing from liquidity pool of @{} in {} epoch.
+                Attached deposit is {} yoctoNear.
+                Exchangeable deposit is {} yoctoStNear.
+                Fee is {:?}.
+                Released storage staking price is {} yoctoNear.
+                Received amount is {} yoctoNear.
+                Old @{} balance is {} yoctoStNear.
+                @{} sent {} yoctoStNear.
+                New @{} balance is {} yoctoStNear.
+                New liquidity pool balance is {} yoctoNear.
+                New liquidity pool balance is {} yoctoStNear.
+                ",
+                &current_account_id_log,
+                self.current_epoch_height,
+                attached_deposit,
+                token_amount,
+                instant_withdraw_fee_self_log,
+                released_storage_staking_price_per_additional_account_log,
+                near_amount,
+                &predecessor_account_id,
+                token_balance_log,
+                &predecessor_account_id,
+                token_amount,
+                &predecessor_account_id,
+                account_balance.token_amount,
+                self.fund.classic_liquidity_balance,
+                self.liquidity_pool.token_amount
+            ).as_str()
+        );
+
+        Promise::new(predecessor_account_id)
+            .transfer(near_amount)
+    }
 
-        if self.fund.is_distributed_on_validators_in_current_epoch {
-            env::panic_str("Fund has already been distributed.");
+    /// Near amount of the delayed withdrawal requested by the liquidity pool on rebalancing.
+    fn get_liquidity_pool_delayed_withdrawal_near_amount(&self) -> Balance {
+        match self.fund.liquidity_pool_delayed_withdrawal {
+            Some(ref delayed_withdrawal) => delayed_withdrawal.near_amount,
+            None => 0
         }
+    }
 
-        self.fund.is_distributed_on_validators_in_current_epoch = true;
+    /// Near value of the whole liquidity pool, it is used for the share calculating.
+    fn get_liquidity_pool_common_near_amount(&self) -> Balance {
+        self.fund.classic_liquidity_balance
+            + self.convert_token_amount_to_near_amount(self.liquidity_pool.token_amount)
+            + self.get_liquidity_pool_delayed_withdrawal_near_amount()
     }
 
-    fn internal_ft_transfer(&mut self, receiver_account_id: AccountId, token_amount: Balance) -> Promise {
-        Self::assert_gas_is_enough();
-        Self::assert_natural_deposit();
+    /// Takes all matured delayed withdrawals of the account and returns the Near amount to be paid, including the released storage staking price.
+    fn take_account_delayed_withdrawal(&mut self, account_id: &AccountId) -> Balance {
+        if !self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.contains_key(account_id)
+            && self.get_legacy_delayed_withdrawal(account_id).is_none() {
+            env::panic_str("Delayed withdrawal account is not registered.");
+        }
 
-        if token_amount == 0 {
-            env::panic_str("Insufficient token amount.");
+        let (legacy_delayed_withdrawal_near_amount, legacy_released_storage_staking_price) = self.take_matured_legacy_delayed_withdrawal(account_id);
+        let (mut delayed_withdrawal_near_amount, mut released_storage_staking_price) = self.take_matured_delayed_withdrawals(account_id);
+        delayed_withdrawal_near_amount += legacy_delayed_withdrawal_near_amount;
+        released_storage_staking_price += legacy_released_storage_staking_price;
+        if delayed_withdrawal_near_amount == 0 {
+            env::panic_str("Wrong epoch for withdrawal.");
         }
 
-        let mut refundable_near_amount = env::attached_deposit();
+        env::log_str(
+            format!(
+                "
+                Delayed withdrawal of @{} taken in {} epoch.
+                Taken amount is {} yoctoNear.
+                Released storage staking price is {} yoctoNear.
+                ",
+                account_id,
+                self.current_epoch_height,
+                delayed_withdrawal_near_amount,
+                released_storage_staking_price
+            ).as_str()
+        );
 
-        let predecessor_account_id = env::predecessor_account_id();
-        let mut predecessor_account_balance = match self.fungible_token.account_registry.get(&predecessor_account_id) {
-            Some(account_balance) => account_balance,
-            None => {
-                env::panic_str("Token account is not registered yet.");
-            }
-        };
+        delayed_withdrawal_near_amount + released_storage_staking_price
+    }
 
-        let mut receiver_account_balance = match self.fungible_token.account_registry.get(&receiver_account_id) {
-            Some(account_balance) => account_balance,
+    /// Removes all delayed withdrawals of the account available for taking and burns the non-fungible tokens representing them.
+    /// Returns the taken Near amount and the released storage staking price.
+    fn take_matured_delayed_withdrawals(&mut self, account_id: &AccountId) -> (Balance, Balance) {
+        let mut account_delayed_withdrawal = match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(account_id) {
+            Some(account_delayed_withdrawal_) => account_delayed_withdrawal_,
             None => {
-                env::panic_str("Token account is not registered yet.");
+                return (0, 0);
             }
         };
 
-        if predecessor_account_balance.token_amount < token_amount {
-            env::panic_str("Token amount exceeded the available token balance.");
+        let (delayed_withdrawal_id_registry, delayed_withdrawal_near_amount) =
+            account_delayed_withdrawal.take_matured_delayed_withdrawals(self.current_epoch_height, &self.config);
+        if delayed_withdrawal_id_registry.is_empty() {
+            return (0, 0);
         }
 
-        predecessor_account_balance.token_amount -= token_amount;
-
-        if let Some(investor_investment) = self.validating.investor_investment_registry.get(&predecessor_account_id) {
-            if (self.convert_token_amount_to_near_amount(predecessor_account_balance.token_amount) + predecessor_account_balance.investment_near_amount)
-                < investor_investment.staked_balance {
-                env::panic_str("Token amount exceeded the available to transfer token amount.");
-            }
+        for delayed_withdrawal_id in delayed_withdrawal_id_registry.iter() {
+            self.fund.delayed_withdrawn_fund.delayed_withdrawal_owner_registry.remove(delayed_withdrawal_id);
         }
 
-        receiver_account_balance.token_amount += token_amount;
+        let token_id_registry: Vec<String> = delayed_withdrawal_id_registry
+            .iter()
+            .map(|delayed_withdrawal_id| delayed_withdrawal_id.to_string())
+            .collect();
+        NftBurn {
+            owner_id: account_id,
+            token_ids: &token_id_registry.iter().map(|token_id| token_id.as_str()).collect::<Vec<&str>>(),
+            authorized_id: None,
+            memo: None
+        }.emit();
 
-        if predecessor_account_balance.token_amount > 0
-            || predecessor_account_id == self.account_registry.self_fee_receiver_account_id
-            || predecessor_account_id == self.account_registry.partner_fee_receiver_account_id {
-            self.fungible_token.account_registry.insert(&predecessor_account_id, &predecessor_account_balance);
-        } else {
-            self.fungible_token.account_registry.remove(&predecessor_account_id);
-            self.fungible_token.accounts_quantity -= 1;
+        let mut released_storage_staking_price = Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal)
+            * (delayed_withdrawal_id_registry.len() as Balance);
 
-            receiver_account_balance.classic_near_amount += predecessor_account_balance.classic_near_amount;
+        if account_delayed_withdrawal.delayed_withdrawal_registry.is_empty() {
+            self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.remove(account_id);
 
-            refundable_near_amount += Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
+            released_storage_staking_price += account_delayed_withdrawal.storage_staking_price;
+        } else {
+            self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.insert(account_id, &account_delayed_withdrawal);
         }
-        self.fungible_token.account_registry.insert(&receiver_account_id, &receiver_account_balance);
 
-        Promise::new(predecessor_account_id)
-            .transfer(refundable_near_amount)
+        self.fund.delayed_withdrawn_fund.balance -= delayed_withdrawal_near_amount;
+
+        (delayed_withdrawal_near_amount, released_storage_staking_price)
     }
 
-    pub fn internal_get_delayed_withdrawal_details(&self, account_id: AccountId) -> Option<DelayedWithdrawalDetails> {
-        self.assert_epoch_is_synchronized();
+    /// Removes the legacy delayed withdrawal of the account if it is available for taking.
+    /// Returns the taken Near amount and the released storage staking price.
+    fn take_matured_legacy_delayed_withdrawal(&mut self, account_id: &AccountId) -> (Balance, Balance) {
+        let legacy_delayed_withdrawal_registry = match self.fund.delayed_withdrawn_fund.legacy_delayed_withdrawal_registry {
+            Some(ref mut legacy_delayed_withdrawal_registry_) => legacy_delayed_withdrawal_registry_,
+            None => {
+                return (0, 0);
+            }
+        };
 
-        if let Some(delayed_withdrawal) = self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(&account_id) {
-            return Some(
-                    DelayedWithdrawalDetails {
-                        epoch_quantity_to_take_delayed_withdrawal: delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(self.current_epoch_height),
-                        near_amount: delayed_withdrawal.near_amount.into()
-                }
-            );
+        let delayed_withdrawal = match legacy_delayed_withdrawal_registry.delayed_withdrawal_registry.get(account_id) {
+            Some(delayed_withdrawal_) => delayed_withdrawal_,
+            None => {
+                return (0, 0);
+            }
+        };
+        if !delayed_withdrawal.can_take_delayed_withdrawal(self.current_epoch_height, &self.config) {
+            return (0, 0);
         }
 
-        None
-    }
+        legacy_delayed_withdrawal_registry.delayed_withdrawal_registry.remove(account_id);
 
-    fn internal_get_total_token_supply(&self) -> Balance {
-        self.assert_epoch_is_synchronized();
+        let released_storage_staking_price = Self::calculate_storage_staking_price(legacy_delayed_withdrawal_registry.storage_usage_per_delayed_withdrawal);
 
-        self.fungible_token.total_supply
-    }
+        self.fund.delayed_withdrawn_fund.balance -= delayed_withdrawal.near_amount;
 
-    fn internal_get_minimum_deposit_amount(&self) -> Balance {
-        MINIMUN_DEPOSIT_AMOUNT
+        (delayed_withdrawal.near_amount, released_storage_staking_price)
     }
 
-    pub fn internal_get_storage_staking_price(&self) -> StorageStakingPrice {
-        StorageStakingPrice {
-            per_delayed_withdrawal_fund_delayed_withdrawal: Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal).into(),
-            per_delayed_withdrawal_fund_investment_withdrawal: Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal).into(),
-            per_fungible_token_account: Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account).into(),
-            per_validating_node_validator: Self::calculate_storage_staking_price(self.validating.storage_usage_per_validator).into(),
-            per_validating_node_investor: Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment).into(),
-            per_validating_node_distribution: Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution).into()
+    /// Delayed withdrawal of the account created before the independent delayed withdrawals of the account were added.
+    fn get_legacy_delayed_withdrawal(&self, account_id: &AccountId) -> Option<DelayedWithdrawal> {
+        match self.fund.delayed_withdrawn_fund.legacy_delayed_withdrawal_registry {
+            Some(ref legacy_delayed_withdrawal_registry) => legacy_delayed_withdrawal_registry.delayed_withdrawal_registry.get(account_id),
+            None => None
         }
     }
 
-    pub fn internal_get_storage_staking_requested_coverage(&self, account_id: AccountId) -> StorageStakingRequestedCoverage {
-        self.assert_epoch_is_synchronized();
-
-        let storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal = Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal);
-
-        let storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal = Self::calculate_storage_staking_price(self.fund.delayed_withdrawn_fund.storage_usage_per_investment_withdrawal);
-
-        let storage_staking_price_per_fungible_token_account = Self::calculate_storage_staking_price(self.fungible_token.storage_usage_per_account);
-
-        let storage_staking_price_per_validating_node_distribution = Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
-
-        let per_method_deposit = if !self.fungible_token.account_registry.contains_key(&account_id) {
-            storage_staking_price_per_fungible_token_account
-        } else {
-            0
-        };
-
-        let per_method_delayed_withdraw = if !self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.contains_key(&account_id) {
-            storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal
-        } else {
-            0
-        };
-
-        let (per_method_deposit_on_validator, per_method_delayed_withdraw_from_validator): (Option<(U128, Vec<(AccountId, U128)>)>, Option<(U128, Vec<(AccountId, U128)>)>) =
-            match self.validating.investor_investment_registry.get(&account_id) {
-            Some(investor_investment) => {
-                let requested_storage_staking_price_per_fungible_token_account = if !self.fungible_token.account_registry.contains_key(&account_id) {
-                    storage_staking_price_per_fungible_token_account
-                } else {
-                    0
-                };
-
-                let requested_storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal = if !self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.contains_key(&account_id) {
-                    storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal
-                } else {
-                    0
-                };
-
-                let mut requested_storage_staking_price_per_distribution_registry: Vec<(AccountId, U128)> = vec![];
-
-                let mut requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry: Vec<(AccountId, U128)> = vec![];
-
-                for validator_account_id in self.validating.validator_registry.keys() {
-                    if !investor_investment.distribution_registry.contains_key(&validator_account_id) {
-                        requested_storage_staking_price_per_distribution_registry.push((validator_account_id, storage_staking_price_per_validating_node_distribution.into()));
-                    } else {
-                        requested_storage_staking_price_per_distribution_registry.push((validator_account_id.clone(), 0.into()));
-
-                        if !self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.contains_key(&validator_account_id) {
-                            requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry.push((validator_account_id, storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal.into()));
-                        } else {
-                            requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry.push((validator_account_id, 0.into()));
-                        }
-                    }
-                }
-
+    /// Near amount and epoch quantity to take the nearest delayed withdrawal of the account.
+    fn get_account_delayed_withdrawal_details(&self, account_id: &AccountId, current_epoch_height: EpochHeight) -> (Balance, u64) {
+        match self.fund.delayed_withdrawn_fund.delayed_withdrawal_registry.get(account_id) {
+            Some(account_delayed_withdrawal) => {
                 (
-                    Some((requested_storage_staking_price_per_fungible_token_account.into(), requested_storage_staking_price_per_distribution_registry)),
-                    Some((requested_storage_staking_price_per_delayed_withdrawal_fund_delayed_withdrawal.into(), requested_storage_staking_price_per_delayed_withdrawal_fund_investment_withdrawal_registry))
+                    account_delayed_withdrawal.near_amount,
+                    account_delayed_withdrawal.get_epoch_quantity_to_take_delayed_withdrawal(current_epoch_height, &self.config)
                 )
             }
-            None => {
-                (None, None)
-            }
-        };
-
-        StorageStakingRequestedCoverage {
-            per_method_deposit: per_method_deposit.into(),
-            per_method_deposit_on_validator,
-            per_method_delayed_withdraw: per_method_delayed_withdraw.into(),
-            per_method_delayed_withdraw_from_validator
+            None => (0, 0)
         }
     }
 
-    fn internal_get_account_balance(&self, account_id: AccountId) -> AccountBalanceDto {
-        match self.fungible_token.account_registry.get(&account_id) {
-            Some(account_balance) => {
-                let common_near_balance = self.convert_token_amount_to_near_amount(account_balance.token_amount)
-                + account_balance.classic_near_amount
-                + account_balance.investment_near_amount;
-
-                match self.validating.investor_investment_registry.get(&account_id) {
-                    Some(investor_investment) => {
-                        if common_near_balance < investor_investment.staked_balance {
-                            env::panic_str("Nonexecutable code. Near balance should be greater then or equal to investment near balance.");
-                        }
+    fn pay_keeper_reward(&mut self, keeper_account_id: AccountId) {
+        let near_amount = self.keeper_reward.take_near_amount_per_call(env::epoch_height());
+        if near_amount > 0 {
+            Promise::new(keeper_account_id)
+                .transfer(near_amount);
+        }
+    }
 
-                        let (mut investment_near_balance_token_coverage, remainder_near_amount) = self.convert_near_amount_to_token_amount(investor_investment.staked_balance);
+    /// Removes the investor setting and returns the storage staking paid on the investor registration to the payer.
+    /// The storage staking that was not paid on the registration (the investor registered before the investor registry
+    /// was added, the allowed validators) is returned to the refund account.
+    fn refund_investor_registration(&mut self, investor_account_id: &AccountId, refund_account_id: AccountId) -> Promise {
+        let mut near_amount = Self::calculate_storage_staking_price(self.validating.storage_usage_per_investor_investment);
 
-                        if remainder_near_amount > 0 {
-                            investment_near_balance_token_coverage += 1;
-                        }
+        match self.investor_registry.investor_setting_registry.remove(investor_account_id) {
+            Some(investor_setting) => {
+                near_amount += Self::calculate_storage_staking_price(
+                    self.investor_registry.storage_usage_per_investor + investor_setting.calculate_additional_storage_usage()
+                );
 
-                        AccountBalanceDto {
-                            base_account_balance: Some(
-                                BaseAccountBalance {
-                                    token_balance: account_balance.token_amount.into(),
-                                    common_near_balance: common_near_balance.into(),
-                                    classic_near_balance: (common_near_balance - investor_investment.staked_balance).into(),
-                                    classic_near_balance_token_coverage: (account_balance.token_amount - investment_near_balance_token_coverage).into()
-                                }
-                            ),
-                            investment_account_balance: Some(
-                                InvestmentAccountBalance {
-                                    near_balance: investor_investment.staked_balance.into(),
-                                    near_balance_token_coverage: investment_near_balance_token_coverage.into()
-                                }
-                            )
-                        }
-                    }
-                    None => {
-                        AccountBalanceDto {
-                            base_account_balance: Some(
-                                BaseAccountBalance {
-                                    token_balance: account_balance.token_amount.into(),
-                                    common_near_balance: common_near_balance.into(),
-                                    classic_near_balance: common_near_balance.into(),
-                                    classic_near_balance_token_coverage: account_balance.token_amount.into(),
-                                }
-                            ),
-                            investment_account_balance: None
-                        }
-                    }
+                let paid_near_amount = investor_setting.storage_staking_price.min(near_amount);
+                if near_amount > paid_near_amount {
+                    Promise::new(refund_account_id)
+                        .transfer(near_amount - paid_near_amount);
                 }
+
+                Promise::new(investor_setting.storage_staking_payer_account_id)
+                    .transfer(paid_near_amount)
             }
             None => {
-                match self.validating.investor_investment_registry.get(&account_id) {
-                    Some(_) => {
-                        AccountBalanceDto {
-                            base_account_balance: None,
-                            investment_account_balance: Some(
-                                InvestmentAccountBalance {
-                                    near_balance: 0.into(),
-                                    near_balance_token_coverage: 0.into()
-                                }
-                            )
-                        }
-                    }
-                    None => {
-                        AccountBalanceDto {
-                            base_account_balance: None,
-                            investment_account_balance: None
-                        }
-                    }
-                }
+                Promise::new(refund_account_id)
+                    .transfer(near_amount)
             }
         }
     }
 
-    fn internal_get_fund(&self) -> FundDto {
-        self.assert_epoch_is_synchronized();
-
-        FundDto {
-            classic_unstaked_balance: self.fund.classic_unstaked_balance.into(),
-            classic_staked_balance: self.fund.classic_staked_balance.into(),
-            investment_staked_balance: self.fund.investment_staked_balance.into(),
-            common_staked_balance: self.fund.get_staked_balance().into(),
-            common_balance: self.fund.get_common_balance().into()
-        }
-    }
-
-    fn internal_get_fee_registry(&self) -> FeeRegistry {
-        self.assert_epoch_is_synchronized();
-        self.assert_authorized_management_only_by_manager();
-
-        self.fee_registry.clone()
+    fn add_withdrawn_moving_investment_near_amount(&mut self, source_validator_account_id: &AccountId, near_amount: Balance) {
+        let withdrawn_near_amount = self.validating.withdrawn_moving_investment_registry.get(source_validator_account_id).unwrap_or(0);
+        self.validating.withdrawn_moving_investment_registry.insert(source_validator_account_id, &(withdrawn_near_amount + near_amount));
+        self.validating.withdrawn_moving_investment_near_amount += near_amount;
     }
 
-    fn internal_get_fee_registry_light(&self) -> FeeRegistryLight {
-        self.assert_epoch_is_synchronized();
-
-        let reward_fee = match self.fee_registry.reward_fee {
-            Some(ref reward_fee_) => Some(reward_fee_.self_fee.clone()),
-            None => None
-        };
-
-        let instant_withdraw_fee = match self.fee_registry.instant_withdraw_fee {
-            Some(ref instant_withdraw_fee_) => Some(instant_withdraw_fee_.self_fee.clone()),
-            None => None
-        };
-
-        FeeRegistryLight {
-            reward_fee,
-            instant_withdraw_fee
+    fn reserve_needed_to_request_near_amount(&mut self, near_amount: Balance, stake_decreasing_type: &StakeDecreasingType) {
+        match stake_decreasing_type {
+            StakeDecreasingType::Classic => {
+                self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount -= near_amount;
+            }
+            StakeDecreasingType::Investment => {
+                self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount -= near_amount;
+            }
         }
     }
 
-    pub fn internal_get_current_epoch_height(&self) -> EpochHeightRegistry {
-        EpochHeightRegistry {
-            pool_epoch_height: self.current_epoch_height,
-            network_epoch_height: env::epoch_height()
+    /// Returns the reserved Near amount and unlocks the validator after the failed requested decrease of the validator stake.
+    fn release_needed_to_request_near_amount(
+        &mut self,
+        validator_account_id: &AccountId,
+        near_amount: Balance,
+        stake_decreasing_type: &StakeDecreasingType
+    ) {
+        match stake_decreasing_type {
+            StakeDecreasingType::Classic => {
+                self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount += near_amount;
+            }
+            StakeDecreasingType::Investment => {
+                self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount += near_amount;
+            }
         }
-    }
 
-    pub fn internal_is_stake_distributed(&self) -> bool {
-        self.fund.is_distributed_on_validators_in_current_epoch
+        if let Some(mut validator) = self.validating.validator_registry.get(validator_account_id) {
+            validator.is_stake_decreasing = false;
+            self.validating.validator_registry.insert(validator_account_id, &validator);
+        }
     }
 
-    pub fn internal_get_investor_investment(&self, account_id: AccountId) -> Option<InvestorInvestmentDto> {
-        self.assert_epoch_is_synchronized();
-
-        let mut distribution_registry: Vec<(AccountId, U128)> = vec![];
-
-        let investor_investment = match self.validating.investor_investment_registry.get(&account_id) {
-            Some(investor_investment_) => investor_investment_,
+    /// Restakes the unstaked Near amount of the redelegation on the target validator.
+    /// Returns the Near amount that remains in the classic staked balance while restaking.
+    fn complete_redelegation(&mut self, source_validator_account_id: &AccountId, requested_to_restaking_near_amount: Balance) -> Balance {
+        let redelegation = match self.validating.redelegation_registry.get(source_validator_account_id) {
+            Some(redelegation_) => redelegation_,
             None => {
-                return None;
+                return 0;
             }
         };
-
-        for validator_account_id in self.validating.validator_registry.keys() {
-            if let Some(staked_balance) = investor_investment.distribution_registry.get(&validator_account_id) {
-                distribution_registry.push((validator_account_id, staked_balance.into()));
-            }
+        if redelegation.unstaked_near_amount == 0 {
+            return 0;
         }
 
-        Some(
-            InvestorInvestmentDto {
-                distribution_registry,
-                staked_balance: investor_investment.staked_balance.into()
-            }
-        )
-    }
-
-    fn internal_get_validator_registry(&self) -> Vec<ValidatorDto> {
-        let mut validator_dto_registry: Vec<ValidatorDto> = vec![];
+        self.validating.redelegation_registry.remove(source_validator_account_id);
 
-        for (account_id, validator) in self.validating.validator_registry.into_iter() {
-            validator_dto_registry.push(
-                ValidatorDto {
-                    account_id,
-                    unstaked_balance: validator.balance.requested_to_withdrawal_near_amount.into(),
-                    classic_staked_balance: validator.balance.classic_near_amount.into(),
-                    investment_staked_balance: validator.balance.investment_near_amount.into(),
-                    is_only_for_investment: validator.is_only_for_investment,
-                    last_update_epoch_height: validator.last_update_epoch_height,
-                    last_classic_stake_increasing_epoch_height: validator.last_classic_stake_increasing_epoch_height
-                }
-            );
-        }
+        Promise::new(redelegation.account_id)
+            .transfer(Self::calculate_storage_staking_price(self.validating.storage_usage_per_redelegation));
 
-        validator_dto_registry
-    }
+        let near_amount = redelegation.unstaked_near_amount.min(requested_to_restaking_near_amount);
 
-    fn internal_get_preffered_validator(&self) -> Option<ValidatorDto> {
-        if let Some(ref preffered_validator_account_id) = self.validating.preffered_validator {
-            let validator = match self.validating.validator_registry.get(preffered_validator_account_id) {
-                Some(validator_) => validator_,
-                None => {
-                    env::panic_str("Nonexecutable code. Object must exist.");
+        match self.validating.validator_registry.get(&redelegation.target_validator_account_id) {
+            Some(target_validator) => {
+                if target_validator.is_retiring || target_validator.is_only_for_investment {
+                    return 0;
                 }
-            };
 
-            return Some(
-                ValidatorDto {
-                    account_id: preffered_validator_account_id.clone(),
-                    unstaked_balance: validator.balance.requested_to_withdrawal_near_amount.into(),
-                    classic_staked_balance: validator.balance.classic_near_amount.into(),
-                    investment_staked_balance: validator.balance.investment_near_amount.into(),
-                    is_only_for_investment: validator.is_only_for_investment,
-                    last_update_epoch_height: validator.last_update_epoch_height,
-                    last_classic_stake_increasing_epoch_height: validator.last_classic_stake_increasing_epoch_height
+                match target_validator.staking_contract_version {
+                    StakingContractVersion::Core => {
+                        classic_validator::ext(redelegation.target_validator_account_id.clone())
+                            .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_DEPOSIT_AND_STAKE)
+                            .with_attached_deposit(near_amount)
+                            .deposit_and_stake()
+                            .then(
+                                Self::ext(env::current_account_id())
+                                    .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_REDELEGATE_CALLBACK)
+                                    .redelegate_callback(
+                                        source_validator_account_id.clone(),
+                                        redelegation.target_validator_account_id,
+                                        near_amount
+                                    )
+                            );
+                    }
                 }
-            )
-        }
 
-        None
+                near_amount
+            }
+            None => 0
+        }
     }
 
-    fn internal_get_aggregated(&self) -> Aggregated {
-        self.assert_epoch_is_synchronized();
+    fn remove_retired_validator(&mut self, validator_account_id: AccountId, validator: Validator) {
+        self.validating.validator_registry.remove(&validator_account_id);
+        self.validating.validators_quantity -= 1;
 
-        Aggregated {
-            unstaked_balance: self.fund.classic_unstaked_balance.into(),
-            staked_balance: self.fund.get_staked_balance().into(),
-            token_total_supply: self.fungible_token.total_supply.into(),
-            token_accounts_quantity: self.fungible_token.accounts_quantity,
-            total_rewards_from_validators_near_amount: self.reward.total_rewards_from_validators_near_amount.into(),
-            reward_fee: self.get_fee_registry_light().reward_fee
-        }
-    }
+        Self::refund_validator_metadata_storage_staking(&validator);
 
-    fn internal_get_requested_to_withdrawal_fund(&self) -> RequestedToWithdrawalFund {
-        let mut investment_withdrawal_registry: Vec<(AccountId, U128)> = vec![];
+        Promise::new(validator.storage_staking_payer_account_id.clone())
+            .transfer(validator.storage_staking_price);
 
-        for validator_account_id in self.validating.validator_registry.keys() {
-            if let Some(investment_withdrawal) = self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
-                investment_withdrawal_registry.push((validator_account_id, investment_withdrawal.near_amount.into()))
-            }
-        }
+        let refundable_near_amount = validator.storage_staking_price + validator.metadata_storage_staking_price;
 
-        RequestedToWithdrawalFund {
-            classic_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount.into(),
-            investment_near_amount: self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount.into(),
-            investment_withdrawal_registry
-        }
+        env::log_str(
+            format!(
+                "
+                Removing retired validator @{} in {} epoch.
+                Released storage staking price is {} yoctoNear.
+                ",
+                validator_account_id,
+                env::epoch_height(),
+                refundable_near_amount
+            ).as_str()
+        );
     }
 
-    pub fn internal_get_full(&self) -> Full {
-        self.assert_epoch_is_synchronized();
-
-        Full {
-            storage_staking_price: self.internal_get_storage_staking_price(),
-            fund: self.internal_get_fund(),
-            total_token_supply: self.internal_get_total_token_supply().into(),
-            requested_to_withdrawal_fund: self.internal_get_requested_to_withdrawal_fund(),
-            fee_registry_light: self.internal_get_fee_registry_light(),
-            minimum_deposit_amount: self.get_minimum_deposit_amount()
+    /// Returns the storage staking of the metadata to the account that paid it.
+    fn refund_validator_metadata_storage_staking(validator: &Validator) {
+        if let Some(ref metadata_storage_staking_payer_account_id) = validator.metadata_storage_staking_payer_account_id {
+            if validator.metadata_storage_staking_price > 0 {
+                Promise::new(metadata_storage_staking_payer_account_id.clone())
+                    .transfer(validator.metadata_storage_staking_price);
+            }
         }
     }
 
-    pub fn internal_get_full_for_account(&self, account_id: AccountId) -> FullForAccount {
-        self.assert_epoch_is_synchronized();
-
-        FullForAccount {
-            full: self.internal_get_full(),
-            account_balance: self.internal_get_account_balance(account_id.clone()),
-            delayed_withdrawal_details: self.internal_get_delayed_withdrawal_details(account_id.clone()),
-            investor_investment: self.get_investor_investment(account_id.clone()),
-            storage_staking_requested_coverage: self.get_storage_staking_requested_coverage(account_id)
+    fn convert_validator_to_dto(&self, account_id: AccountId, validator: Validator) -> ValidatorDto {
+        let is_underperforming = validator.is_underperforming(self.validating.minimum_validator_score);
+
+        ValidatorDto {
+            account_id,
+            unstaked_balance: validator.balance.get_unstaked_balance().into(),
+            classic_staked_balance: validator.balance.classic_near_amount.into(),
+            investment_staked_balance: validator.balance.investment_near_amount.into(),
+            is_only_for_investment: validator.is_only_for_investment,
+            is_retiring: validator.is_retiring,
+            last_update_epoch_height: validator.last_update_epoch_height,
+            last_classic_stake_increasing_epoch_height: validator.last_classic_stake_increasing_epoch_height,
+            name: validator.metadata.name,
+            url: validator.metadata.url,
+            commission: validator.metadata.commission,
+            score: validator.performance.calculate_score(),
+            is_underperforming
         }
     }
 
-    fn internal_ft_total_supply(&self) -> Balance {
-        self.fungible_token.total_supply
+    fn convert_investor_setting_to_dto(account_id: AccountId, investor_setting: InvestorSetting) -> InvestorDto {
+        InvestorDto {
+            investor_account_id: account_id,
+            maximum_staked_balance: investor_setting.maximum_staked_balance.map(|maximum_staked_balance| maximum_staked_balance.into()),
+            allowed_validator_account_ids: investor_setting.allowed_validator_account_ids,
+            deposit_fee: investor_setting.deposit_fee
+        }
     }
 
-    fn internal_ft_balance_of(&self, account_id: AccountId) -> Balance {
-        match self.fungible_token.account_registry.get(&account_id) {
-            Some(account_balance) => account_balance.token_amount,
-            None => 0
+    fn assert_authorized_management_only_by_owner(&self) {
+        if env::predecessor_account_id() != self.account_registry.owner_id {
+            env::panic_str("Unauthorized management. Management must be carried out either by the owner of the pool.");
         }
     }
 
-    fn convert_near_amount_to_token_amount(&self, near_amount: Balance) -> (Balance, Balance) {
-        let common_balance = self.fund.get_common_balance();
+    fn assert_authorized_management(&self) {
+        let predecessor_account_id = env::predecessor_account_id();
 
-        if common_balance == 0 || near_amount == 0 || self.fungible_token.total_supply == 0 {
-            return (near_amount, 0);
+        if predecessor_account_id != self.account_registry.owner_id
+            && predecessor_account_id != self.account_registry.manager_id {
+            env::panic_str("Unauthorized management. Management must be carried out either by the owner or manager of the pool.");
         }
+    }
 
-        let token_amount = (
-            U256::from(near_amount)
-            * U256::from(self.fungible_token.total_supply)
-            / U256::from(common_balance)
-        ).as_u128();
-
-        let remainder_near_amount = near_amount - self.convert_token_amount_to_near_amount(token_amount);
+    /// The pool manager has all roles except the guardian one.
+    fn assert_authorized_management_by_role(&self, role: Role) {
+        let predecessor_account_id = env::predecessor_account_id();
 
+        if predecessor_account_id != self.account_registry.manager_id
+            && !self.role_registry.get(role).contains(&predecessor_account_id) {
+            env::panic_str(format!("Unauthorized management. Management must be carried out either by the manager of the pool or by the {:?} role.", role).as_str());
+        }
+    }
 
-        (token_amount, remainder_near_amount)
+    /// While the council is active, the sensitive management is carried out only by the pool itself on the proposal execution.
+    fn assert_authorized_sensitive_management_only_by_owner(&self) {
+        if self.council.is_active() {
+            self.assert_authorized_management_by_council();
+        } else {
+            self.assert_authorized_management_only_by_owner();
+        }
     }
 
-    fn convert_token_amount_to_near_amount(&self, token_amount: Balance) -> Balance {
-        if self.fungible_token.total_supply == 0 || token_amount == 0 {
-            return token_amount
+    fn assert_authorized_sensitive_management_by_role(&self, role: Role) {
+        if self.council.is_active() {
+            self.assert_authorized_management_by_council();
+        } else {
+            self.assert_authorized_management_by_role(role);
         }
+    }
 
-        (
-            U256::from(token_amount)
-            * U256::from(self.fund.get_common_balance())
-            / U256::from(self.fungible_token.total_supply)
-        ).as_u128()
+    fn assert_authorized_management_by_council(&self) {
+        if env::predecessor_account_id() != env::current_account_id() {
+            env::panic_str("Unauthorized management. Management must be carried out by the council proposal.");
+        }
     }
 
-    fn assert_authorized_management_only_by_manager(&self) {
-        if env::predecessor_account_id() != self.account_registry.manager_id {
-            env::panic_str("Unauthorized management. Management must be carried out either by the manager of the pool.");
+    fn assert_council_member(&self) {
+        if !self.council.is_member(&env::predecessor_account_id()) {
+            env::panic_str("Account is not a council member.");
         }
     }
 
-    fn assert_authorized_management(&self) {
+    fn assert_authorized_pause_management(&self) {
         let predecessor_account_id = env::predecessor_account_id();
 
         if predecessor_account_id != self.account_registry.owner_id
-            && predecessor_account_id != self.account_registry.manager_id {
-            env::panic_str("Unauthorized management. Management must be carried out either by the owner or manager of the pool.");
+            && !self.role_registry.get(Role::Guardian).contains(&predecessor_account_id) {
+            env::panic_str("Unauthorized management. Management must be carried out either by the owner or guardian of the pool.");
+        }
+    }
+
+    fn assert_operation_is_not_paused(&self, operation: PausableOperation) {
+        if self.pause.is_paused(operation) {
+            env::panic_str(format!("{:?} operation is paused.", operation).as_str());
         }
     }
 
@@ -2280,8 +6114,8 @@ impl StakePool {
         }
     }
 
-    fn assert_minimum_deposit() {
-        if env::attached_deposit() < MINIMUN_DEPOSIT_AMOUNT {
+    fn assert_minimum_deposit(&self) {
+        if env::attached_deposit() < self.config.minimum_deposit_amount {
             env::panic_str("Attached deposit less then minimum required deposit.");
         }
     }
@@ -2292,20 +6126,84 @@ impl StakePool {
         }
     }
 
-    fn assert_epoch_is_desynchronized(&self) {
-        if self.current_epoch_height == env::epoch_height() {
-            env::panic_str("Epoch should be in desynchronized state.");
+    fn assert_epoch_phase(&self, epoch_phase: EpochPhase) {
+        if self.calculate_epoch_phase() != epoch_phase {
+            env::panic_str(format!("Epoch should be in {:?} phase.", epoch_phase).as_str());
         }
     }
 
-    fn assert_gas_is_enough() {
-        if env::prepaid_gas() < (Gas::ONE_TERA * MINIMUM_NUMBER_OF_TGAS) {
+    fn assert_gas_is_enough(&self, number_of_tgas: u64) {
+        if !self.is_gas_enough(number_of_tgas) {
             env::panic_str("Not enough Gas quantity.");
         }
     }
 
-    fn is_right_epoch(epoch_height: EpochHeight) -> bool {
-        (epoch_height % EPOCH_QUANTITY_FOR_VALIDATOR_UNSTAKE) == 0
+    fn is_gas_enough(&self, number_of_tgas: u64) -> bool {
+        env::prepaid_gas() >= (Gas::ONE_TERA * number_of_tgas.max(self.config.minimum_number_of_tgas))
+    }
+
+    /// The pool epoch is behind the network epoch, so the exchange rate does not include the rewards of the last epoch.
+    fn is_exchange_rate_stale(&self) -> bool {
+        self.current_epoch_height != env::epoch_height()
+    }
+
+    fn calculate_epoch_phase(&self) -> EpochPhase {
+        let network_epoch_height = env::epoch_height();
+
+        if self.current_epoch_height == network_epoch_height {
+            if self.fund.is_distributed_on_validators_in_current_epoch {
+                return EpochPhase::Synced;
+            }
+
+            return EpochPhase::Distributing;
+        }
+
+        if self.validating.validators_quantity == 0 {
+            return EpochPhase::Finalizing;
+        }
+
+        if !self.are_all_validators_updated() {
+            return EpochPhase::UpdatingValidators;
+        }
+
+        if self.config.is_right_epoch(network_epoch_height) && self.is_stake_decreasing_needed() {
+            return EpochPhase::UnstakingRequested;
+        }
+
+        EpochPhase::Finalizing
+    }
+
+    /// The needed to request Near amount can be covered only by the stake which is still on validators. The part
+    /// that can not be covered, for example, because the stake of retiring or redelegated validator is already
+    /// requested to restaking, is carried into the next right epoch.
+    fn is_stake_decreasing_needed(&self) -> bool {
+        let needed_to_request_classic_near_amount = self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount;
+        let needed_to_request_investment_near_amount = self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount;
+
+        if needed_to_request_classic_near_amount == 0 && needed_to_request_investment_near_amount == 0 {
+            return false;
+        }
+
+        for (validator_account_id, validator) in self.validating.validator_registry.iter() {
+            if needed_to_request_classic_near_amount > 0 && validator.balance.classic_near_amount > 0 {
+                return true;
+            }
+
+            if needed_to_request_investment_near_amount > 0 && validator.balance.investment_near_amount > 0 {
+                if let Some(investment_withdrawal) = self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
+                    if investment_withdrawal.near_amount > 0 {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn are_all_validators_updated(&self) -> bool {
+        self.validating.quantity_of_validators_updated_in_current_epoch / self.validating.validators_quantity != 0
+            && self.validating.quantity_of_validators_updated_in_current_epoch % self.validating.validators_quantity == 0
     }
 
     fn calculate_storage_staking_price(quantity_of_bytes: StorageUsage) -> Balance {
@@ -2325,14 +6223,19 @@ impl StakePool {
         &mut self,
         predecessor_account_id: AccountId,
         validator_account_id: AccountId,
-        attached_deposit: Balance,
-        near_amount: Balance,
-        refundable_near_amount: Balance,
-        token_amount: Balance,
-        near_remainder: Balance,
-        current_epoch_height: EpochHeight,
-        storage_staking_price_per_additional_account: Balance
+        deposit_details: DepositDetails,
+        current_epoch_height: EpochHeight
     ) {
+        let DepositDetails {
+            attached_deposit,
+            near_amount,
+            refundable_near_amount,
+            token_amount,
+            near_remainder,
+            storage_staking_price_per_additional_accounts,
+            ..
+        } = deposit_details;
+
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
         }
@@ -2396,7 +6299,7 @@ impl StakePool {
                 env::epoch_height(),
                 attached_deposit,
                 near_amount,
-                storage_staking_price_per_additional_account,
+                storage_staking_price_per_additional_accounts,
                 refundable_near_amount,
                 &current_account_id_log,
                 self.fungible_token.total_supply - token_amount,
@@ -2421,13 +6324,18 @@ impl StakePool {
         &mut self,
         predecessor_account_id: AccountId,
         validator_account_id: AccountId,
-        near_amount: Balance,
-        attached_deposit: Balance,
-        refundable_near_amount: Balance,
-        token_amount: Balance,
-        near_remainder: Balance,
-        storage_staking_price_per_additional_accounts: Balance
+        deposit_details: DepositDetails
     ) -> bool {
+        let DepositDetails {
+            attached_deposit,
+            near_amount,
+            refundable_near_amount,
+            fee_near_amount,
+            token_amount,
+            near_remainder,
+            storage_staking_price_per_additional_accounts
+        } = deposit_details;
+
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
         }
@@ -2481,6 +6389,10 @@ impl StakePool {
                     Promise::new(predecessor_account_id.clone())
                         .transfer(refundable_near_amount);
                 }
+                if fee_near_amount > 0 {
+                    Promise::new(self.account_registry.self_fee_receiver_account_id.clone())
+                        .transfer(fee_near_amount);
+                }
 
                 let current_account_id_log = env::current_account_id();
                 env::log_str(
@@ -2490,6 +6402,7 @@ impl StakePool {
                         Attached deposit is {} yoctoNear.
                         Exchangeable deposit is {} yoctoNear.
                         Reserved storage staking price is {} yoctoNear.
+                        Deposit fee is {} yoctoNear.
                         Refundable deposit is {} yoctoNear.
                         Old @{} total supply is {} yoctoStNear.
                         Old @{} balance is {} yoctoNear.
@@ -2499,35 +6412,135 @@ impl StakePool {
                         New @{} balance is {} yoctoNear.
                         New @{} total supply is {} yoctoStNear.
                         ",
-                        &validator_account_id,
-                        &current_account_id_log,
+                        &validator_account_id,
+                        &current_account_id_log,
+                        env::epoch_height(),
+                        attached_deposit,
+                        near_amount,
+                        storage_staking_price_per_additional_accounts,
+                        fee_near_amount,
+                        refundable_near_amount,
+                        &current_account_id_log,
+                        self.fungible_token.total_supply - token_amount,
+                        &current_account_id_log,
+                        self.fund.get_common_balance() - near_amount,
+                        &predecessor_account_id,
+                        account_balance.token_amount - token_amount,
+                        &predecessor_account_id,
+                        token_amount,
+                        &predecessor_account_id,
+                        account_balance.token_amount,
+                        &current_account_id_log,
+                        self.fund.get_common_balance(),
+                        &current_account_id_log,
+                        self.fungible_token.total_supply
+                    ).as_str()
+                );
+
+                true
+            }
+            _ => {
+                Promise::new(predecessor_account_id)
+                    .transfer(attached_deposit);
+
+                false
+            }
+        }
+    }
+
+    #[private]
+    pub fn complete_investment_move_callback(
+        &mut self,
+        investor_account_id: AccountId,
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: Balance,
+        started_epoch_height: EpochHeight
+    ) -> bool {
+        if env::promise_results_count() == 0 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                let mut validator = match self.validating.validator_registry.get(&target_validator_account_id) {
+                    Some(validator_) => validator_,
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                };
+                validator.balance.investment_near_amount += near_amount;
+                self.validating.validator_registry.insert(&target_validator_account_id, &validator);
+
+                let mut investor_investment = match self.validating.investor_investment_registry.get(&investor_account_id) {
+                    Some(investor_investment_) => investor_investment_,
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                };
+
+                let mut refundable_near_amount = Self::calculate_storage_staking_price(self.validating.storage_usage_per_investment_move);
+
+                let mut staked_balance = match investor_investment.distribution_registry.get(&target_validator_account_id) {
+                    Some(staked_balance_) => {
+                        refundable_near_amount += Self::calculate_storage_staking_price(self.validating.storage_usage_per_distribution);
+
+                        staked_balance_
+                    }
+                    None => {
+                        investor_investment.distributions_quantity += 1;
+
+                        0
+                    }
+                };
+                staked_balance += near_amount;
+                investor_investment.distribution_registry.insert(&target_validator_account_id, &staked_balance);
+                self.validating.investor_investment_registry.insert(&investor_account_id, &investor_investment);
+
+                self.validating.moving_investment_near_amount -= near_amount;
+
+                Promise::new(investor_account_id.clone())
+                    .transfer(refundable_near_amount);
+
+                let current_account_id_log = env::current_account_id();
+                env::log_str(
+                    format!(
+                        "
+                        Completed investment move of @{} from validator @{} to validator @{} in {} epoch.
+                        Old @{} investment Near amount on validator @{} is {} yoctoNear.
+                        Restaking on validator with {} yoctoNear.
+                        New @{} investment Near amount on validator @{} is {} yoctoNear.
+                        Released storage staking price is {} yoctoNear.
+                        ",
+                        &investor_account_id,
+                        &source_validator_account_id,
+                        &target_validator_account_id,
                         env::epoch_height(),
-                        attached_deposit,
-                        near_amount,
-                        storage_staking_price_per_additional_accounts,
-                        refundable_near_amount,
                         &current_account_id_log,
-                        self.fungible_token.total_supply - token_amount,
-                        &current_account_id_log,
-                        self.fund.get_common_balance() - near_amount,
-                        &predecessor_account_id,
-                        account_balance.token_amount - token_amount,
-                        &predecessor_account_id,
-                        token_amount,
-                        &predecessor_account_id,
-                        account_balance.token_amount,
-                        &current_account_id_log,
-                        self.fund.get_common_balance(),
+                        &target_validator_account_id,
+                        validator.balance.investment_near_amount - near_amount,
+                        near_amount,
                         &current_account_id_log,
-                        self.fungible_token.total_supply
+                        &target_validator_account_id,
+                        validator.balance.investment_near_amount,
+                        refundable_near_amount
                     ).as_str()
                 );
 
                 true
             }
             _ => {
-                Promise::new(predecessor_account_id)
-                    .transfer(attached_deposit);
+                self.add_withdrawn_moving_investment_near_amount(&source_validator_account_id, near_amount);
+
+                self.validating.investment_move_registry.insert(
+                    &investor_account_id,
+                    &InvestmentMove {
+                        source_validator_account_id,
+                        target_validator_account_id,
+                        near_amount,
+                        started_epoch_height
+                    }
+                );
 
                 false
             }
@@ -2605,7 +6618,8 @@ impl StakePool {
         validator_account_id: AccountId,
         near_amount: Balance,
         stake_decreasing_type: StakeDecreasingType,
-        refundable_near_amount: Balance
+        refundable_near_amount: Balance,
+        keeper_account_id: AccountId
     ) -> PromiseOrValue<CallbackResult> {
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
@@ -2627,7 +6641,7 @@ impl StakePool {
                     }
                 };
 
-                let unstaked_remainder = unstaked_balance - validator.balance.requested_to_withdrawal_near_amount;
+                let unstaked_remainder = unstaked_balance - validator.balance.get_unstaked_balance();
 
                 let needed_to_unstake_near_amount = near_amount - unstaked_remainder;
 
@@ -2635,14 +6649,17 @@ impl StakePool {
                     StakingContractVersion::Core => {
                         PromiseOrValue::Promise(
                             classic_validator::ext(validator_account_id.clone())
+                                .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_VALIDATOR_UNSTAKE)
                                 .unstake(needed_to_unstake_near_amount.into())
                                 .then(
                                     Self::ext(env::current_account_id())
+                                        .with_static_gas(Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE_CALLBACK_2)
                                         .requested_decrease_validator_stake_callback_2(
                                             validator_account_id,
                                             near_amount,
                                             stake_decreasing_type,
                                             refundable_near_amount,
+                                            keeper_account_id
                                         )
                                 )
                         )
@@ -2650,6 +6667,8 @@ impl StakePool {
                 }
             }
             _ => {
+                self.release_needed_to_request_near_amount(&validator_account_id, near_amount, &stake_decreasing_type);
+
                 PromiseOrValue::Value(
                     CallbackResult {
                         is_success: false,
@@ -2666,7 +6685,8 @@ impl StakePool {
         validator_account_id: AccountId,
         near_amount: Balance,
         stake_decreasing_type: StakeDecreasingType,
-        refundable_near_amount: Balance
+        refundable_near_amount: Balance,
+        keeper_account_id: AccountId
     ) -> CallbackResult {
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
@@ -2688,7 +6708,6 @@ impl StakePool {
                 match stake_decreasing_type {
                     StakeDecreasingType::Classic => {
                         validator.balance.classic_near_amount -= near_amount;
-                        self.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount -= near_amount;
                     }
                     StakeDecreasingType::Investment => {
                         let mut investment_withdrawal = match self.fund.delayed_withdrawn_fund.investment_withdrawal_registry.get(&validator_account_id) {
@@ -2709,11 +6728,21 @@ impl StakePool {
                         }
 
                         validator.balance.investment_near_amount -= near_amount;
-                        self.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount -= near_amount;
+
+                        let moving_near_amount = near_amount.min(validator.balance.needed_to_request_moving_near_amount);
+                        validator.balance.needed_to_request_moving_near_amount -= moving_near_amount;
+                        validator.balance.requested_to_moving_near_amount += moving_near_amount;
                     }
                 }
 
                 validator.balance.requested_to_withdrawal_near_amount += near_amount;
+                validator.is_stake_decreasing = false;
+
+                let current_epoch_height = env::epoch_height();
+
+                let is_keeper_rewarded = validator.last_stake_decreasing_reward_epoch_height != Some(current_epoch_height);
+                validator.last_stake_decreasing_reward_epoch_height = Some(current_epoch_height);
+
                 self.validating.validator_registry.insert(&validator_account_id, &validator);
 
                 let current_account_id_log = env::current_account_id();
@@ -2747,12 +6776,18 @@ impl StakePool {
                     ).as_str()
                 );
 
+                if is_keeper_rewarded {
+                    self.pay_keeper_reward(keeper_account_id);
+                }
+
                 CallbackResult {
                     is_success: true,
                     network_epoch_height: env::epoch_height()
                 }
             }
             _ => {
+                self.release_needed_to_request_near_amount(&validator_account_id, near_amount, &stake_decreasing_type);
+
                 CallbackResult {
                     is_success: false,
                     network_epoch_height: env::epoch_height()
@@ -2765,7 +6800,10 @@ impl StakePool {
     pub fn take_unstaked_balance_callback(
         &mut self,
         validator_account_id: AccountId,
-        requested_to_withdrawal_near_amount: Balance
+        requested_to_withdrawal_near_amount: Balance,
+        requested_to_restaking_near_amount: Balance,
+        requested_to_moving_near_amount: Balance,
+        keeper_account_id: AccountId
     ) -> CallbackResult {
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
@@ -2780,11 +6818,24 @@ impl StakePool {
                     }
                 };
 
-                self.fund.delayed_withdrawn_fund.balance += requested_to_withdrawal_near_amount;
+                // The Near amount unstaked for the investment moves is kept apart, so only the investment moves restake it.
+                self.fund.delayed_withdrawn_fund.balance += requested_to_withdrawal_near_amount - requested_to_moving_near_amount;
+                if requested_to_moving_near_amount > 0 {
+                    self.add_withdrawn_moving_investment_near_amount(&validator_account_id, requested_to_moving_near_amount);
+                }
+
+                let redelegated_near_amount = self.complete_redelegation(&validator_account_id, requested_to_restaking_near_amount);
+
+                self.fund.classic_staked_balance -= requested_to_restaking_near_amount - redelegated_near_amount;
+                self.fund.classic_unstaked_balance += requested_to_restaking_near_amount - redelegated_near_amount;
 
                 validator.balance.requested_to_withdrawal_near_amount -= requested_to_withdrawal_near_amount;
+                validator.balance.requested_to_restaking_near_amount -= requested_to_restaking_near_amount;
+                validator.balance.requested_to_moving_near_amount -= requested_to_moving_near_amount;
                 self.validating.validator_registry.insert(&validator_account_id, &validator);
 
+                let requested_to_withdrawal_near_amount_log = requested_to_withdrawal_near_amount + requested_to_restaking_near_amount;
+
                 let current_account_id_log = env::current_account_id();
                 env::log_str(
                     format!(
@@ -2805,17 +6856,23 @@ impl StakePool {
                         current_account_id_log,
                         validator.balance.investment_near_amount,
                         current_account_id_log,
-                        validator.balance.requested_to_withdrawal_near_amount + requested_to_withdrawal_near_amount,
-                        requested_to_withdrawal_near_amount,
+                        validator.balance.get_unstaked_balance() + requested_to_withdrawal_near_amount_log,
+                        requested_to_withdrawal_near_amount_log,
                         current_account_id_log,
                         validator.balance.classic_near_amount,
                         current_account_id_log,
                         validator.balance.investment_near_amount,
                         current_account_id_log,
-                        validator.balance.requested_to_withdrawal_near_amount
+                        validator.balance.get_unstaked_balance()
                     ).as_str()
                 );
 
+                if validator.is_retiring && validator.balance.get_balance() == 0 {
+                    self.remove_retired_validator(validator_account_id, validator);
+                }
+
+                self.pay_keeper_reward(keeper_account_id);
+
                 CallbackResult {
                     is_success: true,
                     network_epoch_height: env::epoch_height()
@@ -2834,81 +6891,410 @@ impl StakePool {
     pub fn update_validator_callback(
         &mut self,
         validator_account_id: AccountId,
-        current_epoch_height: EpochHeight
+        current_epoch_height: EpochHeight,
+        keeper_account_id: AccountId
     ) -> CallbackResult {
         if env::promise_results_count() == 0 {
             env::panic_str("Contract expected a result on the callback.");
         }
 
-        match env::promise_result(0) {
-            PromiseResult::Successful(data) => {
-                let new_balance: u128 = match near_sdk::serde_json::from_slice::<U128>(data.as_slice()) {
-                    Ok(new_balance_) => new_balance_.into(),
-                    Err(_) => {
-                        env::panic_str("Nonexecutable code. It should be valid JSON object.");
-                    }
-                };
+        let is_success = match env::promise_result(0) {
+            PromiseResult::Successful(data) => self.process_validator_update(validator_account_id, current_epoch_height, data.as_slice()),
+            _ => false
+        };
+        if is_success {
+            self.pay_keeper_reward(keeper_account_id);
+        }
 
+        CallbackResult {
+            is_success,
+            network_epoch_height: env::epoch_height()
+        }
+    }
+
+    #[private]
+    pub fn update_validators_callback(
+        &mut self,
+        validator_account_id_registry: Vec<AccountId>,
+        current_epoch_height: EpochHeight,
+        keeper_account_id: AccountId
+    ) -> Vec<CallbackResult> {
+        if env::promise_results_count() != validator_account_id_registry.len() as u64 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        let mut callback_result_registry: Vec<CallbackResult> = vec![];
+
+        for (index, validator_account_id) in validator_account_id_registry.into_iter().enumerate() {
+            let is_success = match env::promise_result(index as u64) {
+                PromiseResult::Successful(data) => self.process_validator_update(validator_account_id, current_epoch_height, data.as_slice()),
+                _ => false
+            };
+            if is_success {
+                self.pay_keeper_reward(keeper_account_id.clone());
+            }
+
+            callback_result_registry.push(
+                CallbackResult {
+                    is_success,
+                    network_epoch_height: env::epoch_height()
+                }
+            );
+        }
+
+        callback_result_registry
+    }
+
+    #[private]
+    pub fn decrease_validator_stake_for_restaking_callback(
+        &mut self,
+        validator_account_id: AccountId,
+        near_amount: Balance
+    ) -> bool {
+        if env::promise_results_count() == 0 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
                 let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
                     Some(validator_) => validator_,
                     None => {
                         env::panic_str("Nonexecutable code. Object must exist.");
                     }
                 };
-
-                let staking_rewards_near_amount = new_balance - validator.balance.get_balance();
-
-                validator.last_update_epoch_height = current_epoch_height;
-                validator.balance.classic_near_amount += staking_rewards_near_amount;
-
+                validator.balance.classic_near_amount -= near_amount;
+                validator.balance.requested_to_restaking_near_amount += near_amount;
                 self.validating.validator_registry.insert(&validator_account_id, &validator);
-                self.validating.quantity_of_validators_updated_in_current_epoch += 1;
 
-                self.reward.previous_epoch_rewards_from_validators_near_amount += staking_rewards_near_amount;
+                if let Some(mut redelegation) = self.validating.redelegation_registry.get(&validator_account_id) {
+                    redelegation.unstaked_near_amount = near_amount;
+                    self.validating.redelegation_registry.insert(&validator_account_id, &redelegation);
+                }
 
                 let current_account_id_log = env::current_account_id();
                 env::log_str(
                     format!(
                         "
-                        Updating validator @{} from {} epoch to {} epoch.
+                        Requested decreasing validator stake for restaking from validator @{} in {} epoch.
                         Old @{} classic Near amount on validator is {} yoctoNear.
-                        Old @{} investment Near amount on validator is {} yoctoNear.
-                        Old @{} unstaked near amount from validator is {} yoctoNear.
-                        Received on validator Near amount is {} yoctoNear.
+                        Old @{} unstaked Near amount on validator is {} yoctoNear.
+                        Requested to unstake from validator is {} yoctoNear.
                         New @{} classic Near amount on validator is {} yoctoNear.
-                        New @{} investment Near amount on validator is {} yoctoNear.
-                        New @{} unstaked near amount from validator is {} yoctoNear.
+                        New @{} unstaked Near amount on validator is {} yoctoNear.
                         ",
                         validator_account_id,
-                        current_epoch_height - 1,
-                        current_epoch_height,
-                        current_account_id_log,
-                        validator.balance.classic_near_amount - staking_rewards_near_amount,
+                        env::epoch_height(),
                         current_account_id_log,
-                        validator.balance.investment_near_amount,
+                        validator.balance.classic_near_amount + near_amount,
                         current_account_id_log,
-                        validator.balance.requested_to_withdrawal_near_amount,
-                        staking_rewards_near_amount,
+                        validator.balance.get_unstaked_balance() - near_amount,
+                        near_amount,
                         current_account_id_log,
                         validator.balance.classic_near_amount,
                         current_account_id_log,
-                        validator.balance.investment_near_amount,
+                        validator.balance.get_unstaked_balance()
+                    ).as_str()
+                );
+
+                true
+            }
+            _ => {
+                false
+            }
+        }
+    }
+
+    #[private]
+    pub fn redelegate_callback(
+        &mut self,
+        source_validator_account_id: AccountId,
+        target_validator_account_id: AccountId,
+        near_amount: Balance
+    ) -> bool {
+        if env::promise_results_count() == 0 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                let mut validator = match self.validating.validator_registry.get(&target_validator_account_id) {
+                    Some(validator_) => validator_,
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                };
+                validator.balance.classic_near_amount += near_amount;
+                self.validating.validator_registry.insert(&target_validator_account_id, &validator);
+
+                let current_account_id_log = env::current_account_id();
+                env::log_str(
+                    format!(
+                        "
+                        Redelegation from validator @{} to validator @{} in {} epoch.
+                        Old @{} classic Near amount on validator @{} is {} yoctoNear.
+                        Restaking on validator with {} yoctoNear.
+                        New @{} classic Near amount on validator @{} is {} yoctoNear.
+                        ",
+                        source_validator_account_id,
+                        target_validator_account_id,
+                        env::epoch_height(),
                         current_account_id_log,
-                        validator.balance.requested_to_withdrawal_near_amount
+                        target_validator_account_id,
+                        validator.balance.classic_near_amount - near_amount,
+                        near_amount,
+                        current_account_id_log,
+                        target_validator_account_id,
+                        validator.balance.classic_near_amount
                     ).as_str()
                 );
 
-                CallbackResult {
-                    is_success: true,
-                    network_epoch_height: env::epoch_height()
-                }
+                true
             }
             _ => {
-                CallbackResult {
-                    is_success: false,
-                    network_epoch_height: env::epoch_height()
+                self.fund.classic_staked_balance -= near_amount;
+                self.fund.classic_unstaked_balance += near_amount;
+
+                false
+            }
+        }
+    }
+
+    /// Carries out the action of the executed proposal. The Near amount that the action returns is refunded to the proposer,
+    /// who attached it on the proposal creation.
+    #[private]
+    #[payable]
+    pub fn carry_out_proposal_action(&mut self, action: ProposalAction, proposer_account_id: AccountId) -> PromiseOrValue<()> {
+        match action {
+            ProposalAction::ChangeRewardFee { reward_fee_self, reward_fee_partner } => {
+                self.internal_change_reward_fee(reward_fee_self, reward_fee_partner);
+
+                PromiseOrValue::Value(())
+            }
+            ProposalAction::ChangeInstantWithdrawFee { instant_withdraw_fee_self, instant_withdraw_fee_partner } => {
+                self.internal_change_instant_withdraw_fee(instant_withdraw_fee_self, instant_withdraw_fee_partner);
+
+                PromiseOrValue::Value(())
+            }
+            ProposalAction::AddValidator { validator_account_id, staking_contract_version, is_only_for_investment, is_preferred } => {
+                self.internal_add_validator(validator_account_id, staking_contract_version, is_only_for_investment, is_preferred, proposer_account_id)
+            }
+            ProposalAction::RemoveValidator { validator_account_id } => {
+                PromiseOrValue::Promise(self.internal_remove_validator(validator_account_id))
+            }
+            ProposalAction::Upgrade { .. } => {
+                env::panic_str("Upgrade is carried out with the contract code as the raw input.");
+            }
+            ProposalAction::ChangeCouncil { member_account_ids, threshold, execution_delay_epoch_quantity } => {
+                self.internal_change_council(member_account_ids, threshold, execution_delay_epoch_quantity);
+
+                PromiseOrValue::Value(())
+            }
+            ProposalAction::ApproveValidatorProposal { validator_account_id } => {
+                PromiseOrValue::Promise(self.internal_approve_validator_proposal(validator_account_id))
+            }
+            ProposalAction::ChangeFeeReceivers { self_fee_receiver_account_id, partner_fee_receiver_account_id, is_token_sweeping } => {
+                self.internal_change_fee_receivers(
+                    self_fee_receiver_account_id, partner_fee_receiver_account_id, is_token_sweeping, proposer_account_id
+                )
+            }
+        }
+    }
+
+    #[private]
+    pub fn execute_proposal_callback(&mut self, proposal_id: u64, proposer_account_id: AccountId, near_amount: Balance) -> bool {
+        if env::promise_results_count() == 0 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                true
+            }
+            _ => {
+                env::log_str(format!("Proposal {} action is failed.", proposal_id).as_str());
+
+                if near_amount > 0 {
+                    Promise::new(proposer_account_id)
+                        .transfer(near_amount);
                 }
+
+                false
             }
         }
     }
-}
\ No newline at end of file
+
+    #[private]
+    pub fn update_validator_commission_callback(&mut self, validator_account_id: AccountId) -> bool {
+        if env::promise_results_count() == 0 {
+            env::panic_str("Contract expected a result on the callback.");
+        }
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(data) => {
+                let commission = match near_sdk::serde_json::from_slice::<Fee>(data.as_slice()) {
+                    Ok(commission_) => commission_,
+                    Err(_) => {
+                        env::panic_str("Nonexecutable code. It should be valid JSON object.");
+                    }
+                };
+
+                let mut validator = match self.validating.validator_registry.get(&validator_account_id) {
+                    Some(validator_) => validator_,
+                    None => {
+                        env::panic_str("Nonexecutable code. Object must exist.");
+                    }
+                };
+                validator.metadata.commission = Some(commission);
+                self.validating.validator_registry.insert(&validator_account_id, &validator);
+
+                true
+            }
+            _ => {
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{testing_env, ONE_NEAR};
+    use super::*;
+    use super::super::MAXIMUM_NUMBER_OF_TGAS;
+
+    const MANAGER_ACCOUNT_ID: &str = "manager.near";
+    const USER_ACCOUNT_ID: &str = "user.near";
+    const VALIDATOR_ACCOUNT_ID: &str = "validator.near";
+
+    fn set_context(predecessor_account_id: &str, attached_deposit: Balance, epoch_height: EpochHeight, prepaid_gas: Gas) {
+        testing_env!(
+            VMContextBuilder::new()
+                .current_account_id(AccountId::new_unchecked("pool.near".to_string()))
+                .predecessor_account_id(AccountId::new_unchecked(predecessor_account_id.to_string()))
+                .attached_deposit(attached_deposit)
+                .epoch_height(epoch_height)
+                .prepaid_gas(prepaid_gas)
+                .build()
+        );
+    }
+
+    fn initialize() -> StakePool {
+        set_context(MANAGER_ACCOUNT_ID, 0, 0, Gas::ONE_TERA * MAXIMUM_NUMBER_OF_TGAS);
+
+        StakePool::new(
+            FungibleTokenMetadataDto {
+                name: "Staked Near".to_string(),
+                symbol: "STNEAR".to_string(),
+                icon: None,
+                reference: None,
+                reference_hash: None,
+                decimals: 24
+            },
+            None,
+            AccountId::new_unchecked("self.near".to_string()),
+            AccountId::new_unchecked("partner.near".to_string()),
+            None,
+            None,
+            None,
+            None
+        )
+    }
+
+    fn add_validator(stake_pool: &mut StakePool, is_preferred: bool) {
+        set_context(MANAGER_ACCOUNT_ID, ONE_NEAR, 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_EXECUTION);
+
+        stake_pool.add_validator(AccountId::new_unchecked(VALIDATOR_ACCOUNT_ID.to_string()), StakingContractVersion::Core, false, is_preferred);
+    }
+
+    #[test]
+    fn gas_check_passes_from_the_budget() {
+        let stake_pool = initialize();
+
+        for number_of_tgas in [
+            NUMBER_OF_TGAS_FOR_EXECUTION,
+            NUMBER_OF_TGAS_FOR_DEPOSIT,
+            NUMBER_OF_TGAS_FOR_DEPOSIT_ON_VALIDATOR,
+            NUMBER_OF_TGAS_FOR_COMPLETE_INVESTMENT_MOVE,
+            NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE,
+            NUMBER_OF_TGAS_FOR_REQUESTED_DECREASE_VALIDATOR_STAKE,
+            NUMBER_OF_TGAS_FOR_TAKE_UNSTAKED_BALANCE,
+            NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR,
+            NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR_COMMISSION,
+            NUMBER_OF_TGAS_FOR_TAKE_DELAYED_WITHDRAWAL,
+            NUMBER_OF_TGAS_FOR_REBALANCE_LIQUIDITY_POOL,
+            NUMBER_OF_TGAS_FOR_UPGRADE
+        ] {
+            set_context(USER_ACCOUNT_ID, 0, 0, Gas(Gas::ONE_TERA.0 * number_of_tgas - 1));
+            assert!(!stake_pool.is_gas_enough(number_of_tgas), "{}", number_of_tgas);
+
+            set_context(USER_ACCOUNT_ID, 0, 0, Gas::ONE_TERA * number_of_tgas);
+            assert!(stake_pool.is_gas_enough(number_of_tgas), "{}", number_of_tgas);
+        }
+    }
+
+    #[test]
+    fn minimum_gas_amount_raises_the_budget() {
+        let mut stake_pool = initialize();
+        stake_pool.config.minimum_number_of_tgas = NUMBER_OF_TGAS_FOR_DEPOSIT;
+
+        set_context(USER_ACCOUNT_ID, 0, 0, Gas(Gas::ONE_TERA.0 * NUMBER_OF_TGAS_FOR_DEPOSIT - 1));
+        assert!(!stake_pool.is_gas_enough(NUMBER_OF_TGAS_FOR_EXECUTION));
+
+        set_context(USER_ACCOUNT_ID, 0, 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DEPOSIT);
+        assert!(stake_pool.is_gas_enough(NUMBER_OF_TGAS_FOR_EXECUTION));
+    }
+
+    #[test]
+    fn deposit_on_preferred_validator_fits_into_the_budget() {
+        let mut stake_pool = initialize();
+        add_validator(&mut stake_pool, true);
+        stake_pool.fund.is_distributed_on_validators_in_current_epoch = true;
+
+        set_context(USER_ACCOUNT_ID, 2 * ONE_NEAR, 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DEPOSIT);
+        match stake_pool.deposit(ONE_NEAR.into()) {
+            PromiseOrValue::Promise(_) => {}
+            PromiseOrValue::Value(_) => panic!("Deposit must be staked on the preferred validator.")
+        }
+    }
+
+    #[test]
+    fn increase_validator_stake_fits_into_the_budget() {
+        let mut stake_pool = initialize();
+        add_validator(&mut stake_pool, false);
+
+        set_context(USER_ACCOUNT_ID, 2 * ONE_NEAR, 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_DEPOSIT);
+        stake_pool.deposit(ONE_NEAR.into());
+
+        set_context(MANAGER_ACCOUNT_ID, 0, 0, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_INCREASE_VALIDATOR_STAKE);
+        stake_pool.increase_validator_stake(AccountId::new_unchecked(VALIDATOR_ACCOUNT_ID.to_string()), ONE_NEAR.into());
+    }
+
+    #[test]
+    fn update_validator_fits_into_the_budget() {
+        let mut stake_pool = initialize();
+        add_validator(&mut stake_pool, false);
+
+        set_context(MANAGER_ACCOUNT_ID, 0, 1, Gas::ONE_TERA * NUMBER_OF_TGAS_FOR_UPDATE_VALIDATOR);
+        stake_pool.update_validator(AccountId::new_unchecked(VALIDATOR_ACCOUNT_ID.to_string()));
+    }
+
+    #[test]
+    fn update_validators_fits_into_the_budget_per_validator() {
+        let mut stake_pool = initialize();
+        add_validator(&mut stake_pool, false);
+
+        let number_of_tgas = NUMBER_OF_TGAS_FOR_EXECUTION
+            + NUMBER_OF_TGAS_FOR_VALIDATOR_BALANCE_REQUEST
+            + NUMBER_OF_TGAS_FOR_UPDATE_VALIDATORS_CALLBACK
+            + NUMBER_OF_TGAS_FOR_VALIDATOR_UPDATE_PROCESSING
+            + 2 * NUMBER_OF_TGAS_FOR_FUNCTION_CALL_FEES;
+
+        set_context(MANAGER_ACCOUNT_ID, 0, 1, Gas(Gas::ONE_TERA.0 * number_of_tgas - 1));
+        assert!(!stake_pool.is_gas_enough(number_of_tgas));
+
+        set_context(MANAGER_ACCOUNT_ID, 0, 1, Gas::ONE_TERA * number_of_tgas);
+        stake_pool.update_validators(None, None);
+    }
+}
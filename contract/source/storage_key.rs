@@ -1,6 +1,7 @@
 use near_sdk::AccountId;
 use near_sdk::borsh::{self, BorshSerialize};
 use near_sdk::BorshStorageKey;
+use super::role::Role;
 
 /// Do not change the order of variants.
 /// The number of options must be less than or equal to 256 (1 byte).
@@ -14,5 +15,26 @@ pub enum StorageKey {
     Distribution {
         investor_account_id: AccountId
     },
-    InvestmentWithdrawal
+    InvestmentWithdrawal,
+    Redelegation,
+    InvestmentMove,
+    DelayedWithdrawal {
+        account_id: AccountId
+    },
+    DelayedWithdrawalOwner,
+    LiquidityProvider,
+    StateVersion,
+    RoleAccount {
+        role: Role
+    },
+    Proposal,
+    InvestorSetting,
+    ValidatorProposal,
+    /// Registry, in which objects are temporarily created to measure their storage usage. The key has the same length
+    /// as the keys of the stored registries, so the measuring does not touch the stored objects.
+    StorageUsageMeasurement,
+    /// Registry of the account delayed withdrawals of the state migrated from the layout with one delayed withdrawal
+    /// per account, which is stored with the DelayedWithdrawnFund key.
+    AccountDelayedWithdrawal,
+    WithdrawnMovingInvestment
 }
\ No newline at end of file
@@ -1,11 +1,16 @@
-use near_sdk::{env, StorageUsage, AccountId};
+use near_sdk::{env, StorageUsage, AccountId, Balance};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{UnorderedMap, LookupMap};
+use super::fee::Fee;
 use super::get_account_id_with_maximum_length;
+use super::investment_move::InvestmentMove;
 use super::investor_investment::InvestorInvestment;
+use super::redelegation::Redelegation;
 use super::staking_contract_version::StakingContractVersion;
 use super::storage_key::StorageKey;
 use super::validator::Validator;
+use super::validator_performance::EpochReward;
+use super::QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE;
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct Validating {
@@ -14,15 +19,35 @@ pub struct Validating {
     pub validator_registry: UnorderedMap<AccountId, Validator>,
     /// Registry of investors who are allowed to make an deposit/withdrawal directly on/from the validator.
     pub investor_investment_registry: LookupMap<AccountId, InvestorInvestment>,
+    /// Classic stake moves that are not completed yet.
+    /// AccountId - source validator account id.
+    pub redelegation_registry: UnorderedMap<AccountId, Redelegation>,
+    /// Investor stake moves that are not completed yet.
+    /// AccountId - investor account id.
+    pub investment_move_registry: LookupMap<AccountId, InvestmentMove>,
+    /// Investment Near amount that is unstaked from validators in order to be restaked on other validators.
+    pub moving_investment_near_amount: Balance,
+    /// Part of the moving investment Near amount that is withdrawn from validators and can be restaked.
+    pub withdrawn_moving_investment_near_amount: Balance,
+    /// Withdrawn moving investment Near amount of the investment moves from the validator. Only the investment moves
+    /// from the validator can restake it.
+    /// AccountId - source validator account id.
+    pub withdrawn_moving_investment_registry: LookupMap<AccountId, Balance>,
     pub validators_quantity: u64,
     pub preffered_validator: Option<AccountId>,
+    /// The pool does not stake on validators with a score less than this value. In basis points.
+    pub minimum_validator_score: Option<u64>,
     pub quantity_of_validators_updated_in_current_epoch: u64,
     /// In bytes.
     pub storage_usage_per_validator: StorageUsage,
     /// In bytes.
     pub storage_usage_per_investor_investment: StorageUsage,
     /// In bytes.
-    pub storage_usage_per_distribution: StorageUsage
+    pub storage_usage_per_distribution: StorageUsage,
+    /// In bytes.
+    pub storage_usage_per_redelegation: StorageUsage,
+    /// In bytes.
+    pub storage_usage_per_investment_move: StorageUsage
 }
 
 impl Validating {
@@ -30,27 +55,57 @@ impl Validating {
         Self {
             validator_registry: Self::initialize_validator_registry(),
             investor_investment_registry: Self::initialize_investor_investment_registry(),
+            redelegation_registry: Self::initialize_redelegation_registry(),
+            investment_move_registry: Self::initialize_investment_move_registry(),
+            moving_investment_near_amount: 0,
+            withdrawn_moving_investment_near_amount: 0,
+            withdrawn_moving_investment_registry: Self::initialize_withdrawn_moving_investment_registry(),
             validators_quantity: 0,
             preffered_validator: None,
+            minimum_validator_score: None,
             quantity_of_validators_updated_in_current_epoch: 0,
             storage_usage_per_validator: Self::calculate_storage_usage_per_additional_validator(),
             storage_usage_per_investor_investment: Self::calculate_storage_usage_per_additional_investor_investment(),
-            storage_usage_per_distribution: Self::calculate_storage_usage_per_additional_distribution()
+            storage_usage_per_distribution: Self::calculate_storage_usage_per_additional_distribution(),
+            storage_usage_per_redelegation: Self::calculate_storage_usage_per_additional_redelegation(),
+            storage_usage_per_investment_move: Self::calculate_storage_usage_per_additional_investment_move()
         }
     }
 
-    fn calculate_storage_usage_per_additional_validator() -> StorageUsage {
-        let mut validator_registry = Self::initialize_validator_registry();
+    /// The measuring is done in the separate registry, so it does not touch the stored validators on the state migration.
+    pub fn calculate_storage_usage_per_additional_validator() -> StorageUsage {
+        let mut validator_registry: UnorderedMap<AccountId, Validator> = UnorderedMap::new(StorageKey::StorageUsageMeasurement);
 
         let initial_storage_usage = env::storage_usage();
 
         let account_id = get_account_id_with_maximum_length();
 
-        validator_registry.insert(
-            &account_id, &Validator::new(StakingContractVersion::Core, false)
+        let mut validator = Validator::new(StakingContractVersion::Core, false, account_id.clone(), 0);
+        validator.metadata_storage_staking_payer_account_id = Some(account_id.clone());
+        validator.metadata.commission = Some(
+            Fee {
+                numerator: 0,
+                denominator: 0
+            }
         );
+        for _ in 0..QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE {
+            validator.performance.add_epoch_reward(
+                EpochReward {
+                    epoch_height: 0,
+                    epoch_quantity: 0,
+                    staked_near_amount: 0,
+                    rewards_near_amount: 0
+                }
+            );
+        }
 
-        env::storage_usage() - initial_storage_usage
+        validator_registry.insert(&account_id, &validator);
+
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        validator_registry.remove(&account_id);
+
+        storage_usage
     }
 
     fn calculate_storage_usage_per_additional_investor_investment() -> StorageUsage {
@@ -77,6 +132,54 @@ impl Validating {
         env::storage_usage() - initial_storage_usage
     }
 
+    pub fn calculate_storage_usage_per_additional_redelegation() -> StorageUsage {
+        let mut redelegation_registry = Self::initialize_redelegation_registry();
+
+        let initial_storage_usage = env::storage_usage();
+
+        let account_id = get_account_id_with_maximum_length();
+
+        redelegation_registry.insert(
+            &account_id,
+            &Redelegation {
+                target_validator_account_id: account_id.clone(),
+                near_amount: 0,
+                unstaked_near_amount: 0,
+                account_id: account_id.clone()
+            }
+        );
+
+        env::storage_usage() - initial_storage_usage
+    }
+
+    /// The investment move also pays for the withdrawn moving investment of its source validator, so the entry exists
+    /// only while it is covered by the investment moves from the validator.
+    pub fn calculate_storage_usage_per_additional_investment_move() -> StorageUsage {
+        let mut investment_move_registry = Self::initialize_investment_move_registry();
+        let mut withdrawn_moving_investment_registry = Self::initialize_withdrawn_moving_investment_registry();
+
+        let initial_storage_usage = env::storage_usage();
+
+        let account_id = get_account_id_with_maximum_length();
+
+        investment_move_registry.insert(
+            &account_id,
+            &InvestmentMove {
+                source_validator_account_id: account_id.clone(),
+                target_validator_account_id: account_id.clone(),
+                near_amount: 0,
+                started_epoch_height: env::epoch_height()
+            }
+        );
+        withdrawn_moving_investment_registry.insert(&account_id, &0);
+
+        let storage_usage = env::storage_usage() - initial_storage_usage;
+
+        withdrawn_moving_investment_registry.remove(&account_id);
+
+        storage_usage
+    }
+
     fn initialize_validator_registry() -> UnorderedMap<AccountId, Validator> {
         UnorderedMap::new(StorageKey::Validator)
     }
@@ -84,4 +187,16 @@ impl Validating {
     fn initialize_investor_investment_registry() -> LookupMap<AccountId, InvestorInvestment> {
         LookupMap::new(StorageKey::InvestorInvestment)
     }
+
+    pub fn initialize_redelegation_registry() -> UnorderedMap<AccountId, Redelegation> {
+        UnorderedMap::new(StorageKey::Redelegation)
+    }
+
+    pub fn initialize_investment_move_registry() -> LookupMap<AccountId, InvestmentMove> {
+        LookupMap::new(StorageKey::InvestmentMove)
+    }
+
+    pub fn initialize_withdrawn_moving_investment_registry() -> LookupMap<AccountId, Balance> {
+        LookupMap::new(StorageKey::WithdrawnMovingInvestment)
+    }
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
-use near_sdk::{env, EpochHeight};
+use near_sdk::{env, AccountId, Balance, EpochHeight};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use super::staking_contract_version::StakingContractVersion;
 use super::validator_balance::ValidatorBalance;
+use super::validator_metadata::ValidatorMetadata;
+use super::validator_performance::ValidatorPerformance;
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct Validator {
@@ -15,25 +17,66 @@ pub struct Validator {
     /// investment purposes, this means, that classic staked balance and investment staked balance
     /// can be greater than zero.
     pub is_only_for_investment: bool,
+    /// Validator, which is being withdrawn from the pool. The pool should not stake on retiring validator,
+    /// and classic staked balance is unstaked in the right epoch. The validator is removed when all its
+    /// balances are equal to zero.
+    pub is_retiring: bool,
+    /// Requested decrease of the validator stake is in progress. It is needed in order to not
+    /// allow parallel unstake requests to the validator.
+    pub is_stake_decreasing: bool,
     pub last_update_epoch_height: EpochHeight,
-    pub last_classic_stake_increasing_epoch_height: Option<EpochHeight>
+    pub last_classic_stake_increasing_epoch_height: Option<EpochHeight>,
+    /// The keeper is rewarded for the requested decrease of the validator stake once per validator in the epoch.
+    pub last_stake_decreasing_reward_epoch_height: Option<EpochHeight>,
+    pub metadata: ValidatorMetadata,
+    pub performance: ValidatorPerformance,
+    /// Account that paid the storage staking of the validator. It gets the storage staking back on the validator removal.
+    pub storage_staking_payer_account_id: AccountId,
+    /// Storage staking price paid for the validator without the metadata.
+    pub storage_staking_price: Balance,
+    /// Account that paid the storage staking of the metadata. It gets the storage staking back on the metadata change
+    /// and on the validator removal.
+    pub metadata_storage_staking_payer_account_id: Option<AccountId>,
+    /// Storage staking price paid for the metadata.
+    pub metadata_storage_staking_price: Balance
 }
 
 impl Validator {
     pub fn new(
         staking_contract_version: StakingContractVersion,
-        is_only_for_investment: bool
+        is_only_for_investment: bool,
+        storage_staking_payer_account_id: AccountId,
+        storage_staking_price: Balance
     ) -> Self {
         Self {
             balance: ValidatorBalance {
                 classic_near_amount: 0,
                 investment_near_amount: 0,
-                requested_to_withdrawal_near_amount: 0
+                requested_to_withdrawal_near_amount: 0,
+                requested_to_restaking_near_amount: 0,
+                needed_to_request_moving_near_amount: 0,
+                requested_to_moving_near_amount: 0
             },
             staking_contract_version,
             is_only_for_investment,
+            is_retiring: false,
+            is_stake_decreasing: false,
             last_update_epoch_height: env::epoch_height(),
-            last_classic_stake_increasing_epoch_height: None
+            last_classic_stake_increasing_epoch_height: None,
+            last_stake_decreasing_reward_epoch_height: None,
+            metadata: ValidatorMetadata::new(),
+            performance: ValidatorPerformance::new(),
+            storage_staking_payer_account_id,
+            storage_staking_price,
+            metadata_storage_staking_payer_account_id: None,
+            metadata_storage_staking_price: 0
+        }
+    }
+
+    pub fn is_underperforming(&self, minimum_validator_score: Option<u64>) -> bool {
+        match (minimum_validator_score, self.performance.calculate_score()) {
+            (Some(minimum_validator_score_), Some(score)) => score < minimum_validator_score_,
+            _ => false
         }
     }
 }
\ No newline at end of file
@@ -8,11 +8,21 @@ pub struct ValidatorBalance {
     /// Investment part of valdator total Near amount.
     pub investment_near_amount: Balance,
     /// Requested to withdrawal Near amount.
-    pub requested_to_withdrawal_near_amount: Balance
+    pub requested_to_withdrawal_near_amount: Balance,
+    /// Classic Near amount requested to unstake from the validator in order to stake it on other validators.
+    pub requested_to_restaking_near_amount: Balance,
+    /// Investment Near amount that investors requested to move from the validator and that is not unstaked yet.
+    pub needed_to_request_moving_near_amount: Balance,
+    /// Part of the requested to withdrawal Near amount that is unstaked for the investment moves.
+    pub requested_to_moving_near_amount: Balance
 }
 
 impl ValidatorBalance {
     pub fn get_balance(&self) -> Balance {
-        self.classic_near_amount + self.investment_near_amount + self.requested_to_withdrawal_near_amount
+        self.classic_near_amount + self.investment_near_amount + self.get_unstaked_balance()
+    }
+
+    pub fn get_unstaked_balance(&self) -> Balance {
+        self.requested_to_withdrawal_near_amount + self.requested_to_restaking_near_amount
     }
 }
\ No newline at end of file
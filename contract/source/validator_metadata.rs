@@ -0,0 +1,37 @@
+use near_sdk::StorageUsage;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use std::mem::size_of;
+use super::fee::Fee;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatorMetadata {
+    /// Display name of validator.
+    pub name: Option<String>,
+    /// Address of validator site or page with contact information.
+    pub url: Option<String>,
+    /// Fee that the validator takes from the rewards. Fetched from the validator or recorded by the pool manager.
+    pub commission: Option<Fee>
+}
+
+impl ValidatorMetadata {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            url: None,
+            commission: None
+        }
+    }
+
+    /// Storage usage that is not covered by the storage staking price per validator.
+    /// In bytes.
+    pub fn calculate_additional_storage_usage(&self) -> StorageUsage {
+        Self::calculate_string_storage_usage(&self.name) + Self::calculate_string_storage_usage(&self.url)
+    }
+
+    fn calculate_string_storage_usage(value: &Option<String>) -> StorageUsage {
+        match value {
+            Some(value_) => (size_of::<u32>() + value_.len()) as StorageUsage,
+            None => 0
+        }
+    }
+}
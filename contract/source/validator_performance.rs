@@ -0,0 +1,103 @@
+use near_sdk::{Balance, EpochHeight};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::EPOCH_QUANTITY_PER_YEAR;
+use super::QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE;
+use super::stake_pool::U256;
+
+/// Basis points in one unit.
+const BASIS_POINTS: u64 = 10000;
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EpochReward {
+    /// Epoch in which the validator was updated.
+    pub epoch_height: EpochHeight,
+    /// Quantity of epochs passed since the previous validator update.
+    pub epoch_quantity: u64,
+    /// Near amount staked on the validator before the update.
+    pub staked_near_amount: Balance,
+    /// Near amount received from the validator as staking rewards.
+    pub rewards_near_amount: Balance
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatorPerformance {
+    /// Ring buffer with the rewards received in the last QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE updates.
+    pub epoch_reward_history: Vec<EpochReward>,
+    /// Position in the epoch_reward_history which is overwritten on the next update when the history is full.
+    pub next_epoch_reward_index: u64
+}
+
+impl ValidatorPerformance {
+    pub fn new() -> Self {
+        Self {
+            epoch_reward_history: Vec::new(),
+            next_epoch_reward_index: 0
+        }
+    }
+
+    pub fn add_epoch_reward(&mut self, epoch_reward: EpochReward) {
+        if (self.epoch_reward_history.len() as u64) < QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE {
+            self.epoch_reward_history.push(epoch_reward);
+        } else {
+            self.epoch_reward_history[self.next_epoch_reward_index as usize] = epoch_reward;
+        }
+
+        self.next_epoch_reward_index = (self.next_epoch_reward_index + 1) % QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE;
+    }
+
+    /// Returns the history from the oldest to the newest epoch reward.
+    pub fn get_epoch_reward_history(&self) -> Vec<&EpochReward> {
+        if (self.epoch_reward_history.len() as u64) < QUANTITY_OF_EPOCHS_IN_VALIDATOR_PERFORMANCE {
+            return self.epoch_reward_history.iter().collect();
+        }
+
+        let (newest, oldest) = self.epoch_reward_history.split_at(self.next_epoch_reward_index as usize);
+
+        oldest.iter().chain(newest.iter()).collect()
+    }
+
+    /// Annual percentage yield extrapolated from the history. In basis points.
+    pub fn calculate_trailing_apy(&self) -> Option<u64> {
+        let mut rewards_near_amount = U256::zero();
+        let mut staked_near_amount_per_epoch = U256::zero();
+
+        for epoch_reward in self.epoch_reward_history.iter() {
+            rewards_near_amount += U256::from(epoch_reward.rewards_near_amount);
+            staked_near_amount_per_epoch += U256::from(epoch_reward.staked_near_amount) * U256::from(epoch_reward.epoch_quantity);
+        }
+
+        if staked_near_amount_per_epoch.is_zero() {
+            return None;
+        }
+
+        Some(
+            (
+                rewards_near_amount
+                * U256::from(EPOCH_QUANTITY_PER_YEAR)
+                * U256::from(BASIS_POINTS)
+                / staked_near_amount_per_epoch
+            ).low_u64()
+        )
+    }
+
+    /// Quantity of epochs in the history for which the validator did not bring rewards.
+    pub fn get_missed_epochs_quantity(&self) -> u64 {
+        self.epoch_reward_history
+            .iter()
+            .filter(|epoch_reward| epoch_reward.rewards_near_amount == 0)
+            .map(|epoch_reward| epoch_reward.epoch_quantity)
+            .sum()
+    }
+
+    /// Trailing APY weighted by the share of epochs in which the validator brought rewards. In basis points.
+    pub fn calculate_score(&self) -> Option<u64> {
+        let trailing_apy = self.calculate_trailing_apy()?;
+
+        let epoch_quantity: u64 = self.epoch_reward_history
+            .iter()
+            .map(|epoch_reward| epoch_reward.epoch_quantity)
+            .sum();
+
+        Some(trailing_apy * (epoch_quantity - self.get_missed_epochs_quantity()) / epoch_quantity)
+    }
+}
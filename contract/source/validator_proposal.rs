@@ -0,0 +1,14 @@
+use near_sdk::{AccountId, Balance};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use super::staking_contract_version::StakingContractVersion;
+
+/// Validator proposed by the investor. It is added only for investment after the approval.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatorProposal {
+    pub investor_account_id: AccountId,
+    pub staking_contract_version: StakingContractVersion,
+    /// Storage staking price paid by the investor for the validator.
+    pub near_amount: Balance,
+    /// Storage staking price paid by the investor for the proposal.
+    pub storage_staking_price: Balance
+}
@@ -0,0 +1,499 @@
+use near_sdk::{env, AccountId, Balance, EpochHeight, IntoStorageKey, StorageUsage};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, UnorderedMap};
+use super::account_registry::AccountRegistry;
+use super::delayed_withdrawal::DelayedWithdrawal;
+use super::delayed_withdrawn_fund::DelayedWithdrawnFund;
+use super::fee_registry::FeeRegistry;
+use super::fund::Fund;
+use super::fungible_token::FungibleToken;
+use super::investment_withdrawal::InvestmentWithdrawal;
+use super::investor_investment::InvestorInvestment;
+use super::legacy_delayed_withdrawal_registry::LegacyDelayedWithdrawalRegistry;
+use super::reward::Reward;
+use super::stake_pool::StakePool;
+use super::staking_contract_version::StakingContractVersion;
+use super::storage_key::StorageKey;
+use super::validating::Validating;
+use super::validator::Validator;
+use super::validator_balance::ValidatorBalance;
+use super::validator_metadata::ValidatorMetadata;
+use super::validator_performance::ValidatorPerformance;
+use super::STATE_VERSION;
+
+/// The pool state layout of the contract deployed before the state versioning was added.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct StakePoolV1 {
+    pub account_registry: AccountRegistryV1,
+    pub fungible_token: FungibleToken,
+    pub fund: FundV1,
+    pub fee_registry: FeeRegistry,
+    pub validating: ValidatingV1,
+    pub current_epoch_height: EpochHeight,
+    pub reward: Reward
+}
+
+/// The account registry layout before the two-step ownership transfer was added.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AccountRegistryV1 {
+    pub owner_id: AccountId,
+    pub manager_id: AccountId,
+    pub self_fee_receiver_account_id: AccountId,
+    pub partner_fee_receiver_account_id: AccountId
+}
+
+impl AccountRegistryV1 {
+    pub fn migrate(self) -> AccountRegistry {
+        AccountRegistry {
+            owner_id: self.owner_id,
+            manager_id: self.manager_id,
+            pending_owner_id: None,
+            pending_manager_id: None,
+            self_fee_receiver_account_id: self.self_fee_receiver_account_id,
+            partner_fee_receiver_account_id: self.partner_fee_receiver_account_id
+        }
+    }
+}
+
+/// The fund layout with one delayed withdrawal per account.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct FundV1 {
+    pub classic_unstaked_balance: Balance,
+    pub classic_staked_balance: Balance,
+    pub classic_liquidity_balance: Balance,
+    pub investment_staked_balance: Balance,
+    pub delayed_withdrawn_fund: DelayedWithdrawnFundV1,
+    pub is_distributed_on_validators_in_current_epoch: bool
+}
+
+impl FundV1 {
+    pub fn migrate(self) -> Fund {
+        Fund {
+            classic_unstaked_balance: self.classic_unstaked_balance,
+            classic_staked_balance: self.classic_staked_balance,
+            classic_liquidity_balance: self.classic_liquidity_balance,
+            investment_staked_balance: self.investment_staked_balance,
+            delayed_withdrawn_fund: self.delayed_withdrawn_fund.migrate(),
+            liquidity_pool_delayed_withdrawal: None,
+            is_distributed_on_validators_in_current_epoch: self.is_distributed_on_validators_in_current_epoch
+        }
+    }
+}
+
+/// The delayed withdrawn fund layout with one delayed withdrawal per account.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DelayedWithdrawnFundV1 {
+    pub delayed_withdrawal_registry: LookupMap<AccountId, DelayedWithdrawal>,
+    pub investment_withdrawal_registry: LookupMap<AccountId, InvestmentWithdrawal>,
+    pub needed_to_request_classic_near_amount: Balance,
+    pub needed_to_request_investment_near_amount: Balance,
+    pub balance: Balance,
+    pub storage_usage_per_delayed_withdrawal: StorageUsage,
+    pub storage_usage_per_investment_withdrawal: StorageUsage
+}
+
+impl DelayedWithdrawnFundV1 {
+    /// The only delayed withdrawal of the account is kept in the legacy registry until it is taken, and the new
+    /// delayed withdrawals are stored in the registry with the separate key.
+    pub fn migrate(self) -> DelayedWithdrawnFund {
+        DelayedWithdrawnFund {
+            delayed_withdrawal_registry: LookupMap::new(StorageKey::AccountDelayedWithdrawal),
+            delayed_withdrawal_owner_registry: DelayedWithdrawnFund::initialize_delayed_withdrawal_owner_registry(),
+            next_delayed_withdrawal_id: 0,
+            investment_withdrawal_registry: self.investment_withdrawal_registry,
+            needed_to_request_classic_near_amount: self.needed_to_request_classic_near_amount,
+            queued_to_request_classic_near_amount: 0,
+            needed_to_request_investment_near_amount: self.needed_to_request_investment_near_amount,
+            balance: self.balance,
+            storage_usage_per_account_delayed_withdrawal: DelayedWithdrawnFund::calculate_storage_usage_per_additional_account_delayed_withdrawal(),
+            storage_usage_per_delayed_withdrawal: DelayedWithdrawnFund::calculate_storage_usage_per_additional_delayed_withdrawal(),
+            storage_usage_per_investment_withdrawal: self.storage_usage_per_investment_withdrawal,
+            legacy_delayed_withdrawal_registry: Some(
+                LegacyDelayedWithdrawalRegistry {
+                    delayed_withdrawal_registry: self.delayed_withdrawal_registry,
+                    storage_usage_per_delayed_withdrawal: self.storage_usage_per_delayed_withdrawal
+                }
+            )
+        }
+    }
+}
+
+/// The validating layout before the validator retirement, redelegation and investment moves were added.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatingV1 {
+    pub validator_registry: UnorderedMap<AccountId, ValidatorV1>,
+    pub investor_investment_registry: LookupMap<AccountId, InvestorInvestment>,
+    pub validators_quantity: u64,
+    pub preffered_validator: Option<AccountId>,
+    pub quantity_of_validators_updated_in_current_epoch: u64,
+    pub storage_usage_per_validator: StorageUsage,
+    pub storage_usage_per_investor_investment: StorageUsage,
+    pub storage_usage_per_distribution: StorageUsage
+}
+
+impl ValidatingV1 {
+    /// The payer of the stored validators is not known, so the storage staking is returned to the account
+    /// that got it back before the payer was stored.
+    pub fn migrate(self, storage_staking_payer_account_id: AccountId, storage_staking_price_per_validator: Balance) -> Validating {
+        Validating {
+            validator_registry: migrate_registry(
+                self.validator_registry,
+                |validator_v1| validator_v1.migrate(storage_staking_payer_account_id.clone(), storage_staking_price_per_validator)
+            ),
+            investor_investment_registry: self.investor_investment_registry,
+            redelegation_registry: Validating::initialize_redelegation_registry(),
+            investment_move_registry: Validating::initialize_investment_move_registry(),
+            moving_investment_near_amount: 0,
+            withdrawn_moving_investment_near_amount: 0,
+            withdrawn_moving_investment_registry: Validating::initialize_withdrawn_moving_investment_registry(),
+            validators_quantity: self.validators_quantity,
+            preffered_validator: self.preffered_validator,
+            minimum_validator_score: None,
+            quantity_of_validators_updated_in_current_epoch: self.quantity_of_validators_updated_in_current_epoch,
+            storage_usage_per_validator: Validating::calculate_storage_usage_per_additional_validator(),
+            storage_usage_per_investor_investment: self.storage_usage_per_investor_investment,
+            storage_usage_per_distribution: self.storage_usage_per_distribution,
+            storage_usage_per_redelegation: Validating::calculate_storage_usage_per_additional_redelegation(),
+            storage_usage_per_investment_move: Validating::calculate_storage_usage_per_additional_investment_move()
+        }
+    }
+}
+
+/// The validator layout before the validator metadata, performance, retirement and redelegation were added.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatorV1 {
+    pub balance: ValidatorBalanceV1,
+    pub staking_contract_version: StakingContractVersion,
+    pub is_only_for_investment: bool,
+    pub last_update_epoch_height: EpochHeight,
+    pub last_classic_stake_increasing_epoch_height: Option<EpochHeight>
+}
+
+impl ValidatorV1 {
+    pub fn migrate(self, storage_staking_payer_account_id: AccountId, storage_staking_price: Balance) -> Validator {
+        Validator {
+            balance: ValidatorBalance {
+                classic_near_amount: self.balance.classic_near_amount,
+                investment_near_amount: self.balance.investment_near_amount,
+                requested_to_withdrawal_near_amount: self.balance.requested_to_withdrawal_near_amount,
+                requested_to_restaking_near_amount: 0,
+                needed_to_request_moving_near_amount: 0,
+                requested_to_moving_near_amount: 0
+            },
+            staking_contract_version: self.staking_contract_version,
+            is_only_for_investment: self.is_only_for_investment,
+            is_retiring: false,
+            is_stake_decreasing: false,
+            last_update_epoch_height: self.last_update_epoch_height,
+            last_classic_stake_increasing_epoch_height: self.last_classic_stake_increasing_epoch_height,
+            last_stake_decreasing_reward_epoch_height: None,
+            metadata: ValidatorMetadata::new(),
+            performance: ValidatorPerformance::new(),
+            storage_staking_payer_account_id,
+            storage_staking_price,
+            metadata_storage_staking_payer_account_id: None,
+            metadata_storage_staking_price: 0
+        }
+    }
+}
+
+/// The validator balance layout before the redelegation was added.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ValidatorBalanceV1 {
+    pub classic_near_amount: Balance,
+    pub investment_near_amount: Balance,
+    pub requested_to_withdrawal_near_amount: Balance
+}
+
+/// Rewrites the stored values in the next layout. The registry keeps its storage key,
+/// so every stored value is replaced in place.
+pub fn migrate_registry<T: BorshDeserialize + BorshSerialize, U: BorshDeserialize + BorshSerialize>(
+    registry: UnorderedMap<AccountId, T>,
+    migrate: impl Fn(T) -> U
+) -> UnorderedMap<AccountId, U> {
+    let mut migrated_registry: UnorderedMap<AccountId, U> = match registry.try_to_vec() {
+        Ok(registry_) => {
+            match UnorderedMap::try_from_slice(&registry_) {
+                Ok(migrated_registry_) => migrated_registry_,
+                Err(_) => {
+                    env::panic_str("Nonexecutable code. Object must be deserializable.");
+                }
+            }
+        }
+        Err(_) => {
+            env::panic_str("Nonexecutable code. Object must be serializable.");
+        }
+    };
+
+    for (account_id, value) in registry.to_vec() {
+        migrated_registry.insert(&account_id, &migrate(value));
+    }
+
+    migrated_registry
+}
+
+/// All pool state layouts that can be stored by the deployed contract code.
+/// The last variant is the current layout.
+pub enum VersionedStakePool {
+    V1(Box<StakePoolV1>),
+    V2(Box<StakePool>)
+}
+
+impl VersionedStakePool {
+    /// Reads the stored state according to the stored state version.
+    /// The state without the version is the state of the contract deployed before the versioning was added, so it has
+    /// the first layout. The state with the stored version N has the layout N + 1.
+    pub fn read() -> Self {
+        match Self::read_state_version() {
+            None => Self::V1(Box::new(Self::read_state())),
+            Some(1) => Self::V2(Box::new(Self::read_state())),
+            _ => {
+                env::panic_str("State version is not supported.");
+            }
+        }
+    }
+
+    pub fn write_state_version() {
+        env::storage_write(&StorageKey::StateVersion.into_storage_key(), &[STATE_VERSION]);
+    }
+
+    fn read_state_version() -> Option<u8> {
+        match env::storage_read(&StorageKey::StateVersion.into_storage_key()) {
+            Some(state_version) => {
+                match state_version.as_slice() {
+                    [state_version_] => Some(*state_version_),
+                    _ => {
+                        env::panic_str("State version is not valid.");
+                    }
+                }
+            }
+            None => None
+        }
+    }
+
+    fn read_state<T: BorshDeserialize>() -> T {
+        match env::state_read() {
+            Some(state) => state,
+            None => {
+                env::panic_str("Contract state is not initialized.");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_sdk::borsh::BorshSerialize;
+    use super::*;
+
+    /// Borsh bytes of the stored objects written field by field in the order of the first layout.
+    struct Fixture {
+        data: Vec<u8>
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                data: Vec::new()
+            }
+        }
+
+        fn balance(mut self, value: Balance) -> Self {
+            self.data.extend_from_slice(&value.to_le_bytes());
+            self
+        }
+
+        fn number(mut self, value: u64) -> Self {
+            self.data.extend_from_slice(&value.to_le_bytes());
+            self
+        }
+
+        fn flag(mut self, value: bool) -> Self {
+            self.data.push(value as u8);
+            self
+        }
+
+        fn bytes(mut self, value: &[u8]) -> Self {
+            self.data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            self.data.extend_from_slice(value);
+            self
+        }
+
+        fn account_id(self, value: &str) -> Self {
+            self.bytes(value.as_bytes())
+        }
+
+        fn tag(mut self, value: u8) -> Self {
+            self.data.push(value);
+            self
+        }
+
+        /// Stored collection is represented by its storage prefix.
+        fn unordered_map(self, storage_key: u8, length: u64) -> Self {
+            self.bytes(&[storage_key, b'i'])
+                .number(length)
+                .bytes(&[storage_key, b'k'])
+                .number(length)
+                .bytes(&[storage_key, b'v'])
+        }
+    }
+
+    const INVESTOR_INVESTMENT_STORAGE_KEY: u8 = 0;
+    const FUNGIBLE_TOKEN_STORAGE_KEY: u8 = 1;
+    const FUNGIBLE_TOKEN_METADATA_STORAGE_KEY: u8 = 2;
+    const VALIDATOR_STORAGE_KEY: u8 = 3;
+    const DELAYED_WITHDRAWN_FUND_STORAGE_KEY: u8 = 4;
+    const INVESTMENT_WITHDRAWAL_STORAGE_KEY: u8 = 6;
+
+    fn get_first_layout_state() -> Vec<u8> {
+        Fixture::new()
+            // Account registry.
+            .account_id("owner.near")
+            .account_id("manager.near")
+            .account_id("self_fee_receiver.near")
+            .account_id("partner_fee_receiver.near")
+            // Fungible token.
+            .balance(1_000)
+            .bytes(&[FUNGIBLE_TOKEN_STORAGE_KEY])
+            .number(3)
+            .bytes(&[FUNGIBLE_TOKEN_METADATA_STORAGE_KEY])
+            .number(154)
+            // Fund.
+            .balance(100)
+            .balance(900)
+            .balance(0)
+            .balance(50)
+            // Delayed withdrawn fund.
+            .bytes(&[DELAYED_WITHDRAWN_FUND_STORAGE_KEY])
+            .bytes(&[INVESTMENT_WITHDRAWAL_STORAGE_KEY])
+            .balance(30)
+            .balance(20)
+            .balance(10)
+            .number(105)
+            .number(179)
+            .flag(true)
+            // Fee registry.
+            .tag(1)
+            .number(1)
+            .number(10)
+            .tag(1)
+            .number(1)
+            .number(2)
+            .tag(0)
+            // Validating.
+            .unordered_map(VALIDATOR_STORAGE_KEY, 2)
+            .bytes(&[INVESTOR_INVESTMENT_STORAGE_KEY])
+            .number(2)
+            .tag(1)
+            .account_id("validator.near")
+            .number(2)
+            .number(225)
+            .number(180)
+            .number(116)
+            // Current epoch height.
+            .number(1_200)
+            // Reward.
+            .balance(7)
+            .balance(70)
+            .data
+    }
+
+    fn get_first_layout_validator() -> Vec<u8> {
+        Fixture::new()
+            .balance(500)
+            .balance(40)
+            .balance(5)
+            .tag(0)
+            .flag(false)
+            .number(1_199)
+            .tag(1)
+            .number(1_196)
+            .data
+    }
+
+    #[test]
+    fn first_layout_state_round_trip() {
+        let state = get_first_layout_state();
+
+        let stake_pool_v1 = StakePoolV1::try_from_slice(&state).unwrap();
+
+        assert_eq!(stake_pool_v1.account_registry.owner_id.as_str(), "owner.near");
+        assert_eq!(stake_pool_v1.account_registry.partner_fee_receiver_account_id.as_str(), "partner_fee_receiver.near");
+        assert_eq!(stake_pool_v1.fungible_token.total_supply, 1_000);
+        assert_eq!(stake_pool_v1.fungible_token.accounts_quantity, 3);
+        assert_eq!(stake_pool_v1.fund.classic_staked_balance, 900);
+        assert_eq!(stake_pool_v1.fund.investment_staked_balance, 50);
+        assert_eq!(stake_pool_v1.fund.delayed_withdrawn_fund.needed_to_request_classic_near_amount, 30);
+        assert_eq!(stake_pool_v1.fund.delayed_withdrawn_fund.needed_to_request_investment_near_amount, 20);
+        assert_eq!(stake_pool_v1.fund.delayed_withdrawn_fund.balance, 10);
+        assert_eq!(stake_pool_v1.fund.delayed_withdrawn_fund.storage_usage_per_delayed_withdrawal, 105);
+        assert!(stake_pool_v1.fund.is_distributed_on_validators_in_current_epoch);
+        assert_eq!(stake_pool_v1.fee_registry.reward_fee.as_ref().unwrap().self_fee.denominator, 10);
+        assert!(stake_pool_v1.fee_registry.instant_withdraw_fee.is_none());
+        assert_eq!(
+            stake_pool_v1.validating.validator_registry.try_to_vec().unwrap(),
+            Fixture::new().unordered_map(VALIDATOR_STORAGE_KEY, 2).data
+        );
+        assert_eq!(stake_pool_v1.validating.validators_quantity, 2);
+        assert_eq!(stake_pool_v1.validating.preffered_validator.as_ref().unwrap().as_str(), "validator.near");
+        assert_eq!(stake_pool_v1.validating.storage_usage_per_distribution, 116);
+        assert_eq!(stake_pool_v1.current_epoch_height, 1_200);
+        assert_eq!(stake_pool_v1.reward.total_rewards_from_validators_near_amount, 70);
+
+        assert_eq!(stake_pool_v1.try_to_vec().unwrap(), state);
+    }
+
+    #[test]
+    fn first_layout_state_is_not_read_as_next_layout() {
+        assert!(StakePool::try_from_slice(&get_first_layout_state()).is_err());
+    }
+
+    #[test]
+    fn first_layout_validator_round_trip() {
+        let stored_validator = get_first_layout_validator();
+
+        let validator_v1 = ValidatorV1::try_from_slice(&stored_validator).unwrap();
+        assert_eq!(validator_v1.try_to_vec().unwrap(), stored_validator);
+
+        let validator = Validator::try_from_slice(
+            &validator_v1.migrate(AccountId::new_unchecked("manager.near".to_string()), 1_000).try_to_vec().unwrap()
+        ).unwrap();
+        assert_eq!(validator.balance.classic_near_amount, 500);
+        assert_eq!(validator.balance.investment_near_amount, 40);
+        assert_eq!(validator.balance.requested_to_withdrawal_near_amount, 5);
+        assert_eq!(validator.balance.requested_to_restaking_near_amount, 0);
+        assert!(!validator.is_only_for_investment);
+        assert!(!validator.is_retiring);
+        assert!(!validator.is_stake_decreasing);
+        assert_eq!(validator.last_update_epoch_height, 1_199);
+        assert_eq!(validator.last_classic_stake_increasing_epoch_height, Some(1_196));
+        assert!(validator.last_stake_decreasing_reward_epoch_height.is_none());
+        assert!(validator.metadata.name.is_none());
+        assert!(validator.performance.epoch_reward_history.is_empty());
+        assert_eq!(validator.storage_staking_payer_account_id.as_str(), "manager.near");
+        assert_eq!(validator.storage_staking_price, 1_000);
+    }
+
+    #[test]
+    fn first_layout_delayed_withdrawal_round_trip() {
+        let stored_delayed_withdrawal = Fixture::new()
+            .balance(25)
+            .number(1_197)
+            .data;
+
+        let delayed_withdrawal = DelayedWithdrawal::try_from_slice(&stored_delayed_withdrawal).unwrap();
+        assert_eq!(delayed_withdrawal.near_amount, 25);
+        assert_eq!(delayed_withdrawal.started_epoch_height, 1_197);
+
+        assert_eq!(delayed_withdrawal.try_to_vec().unwrap(), stored_delayed_withdrawal);
+    }
+
+    #[test]
+    fn first_layout_validator_registry_keeps_storage_key() {
+        let validating_v1 = StakePoolV1::try_from_slice(&get_first_layout_state()).unwrap().validating;
+
+        let validator_registry: UnorderedMap<AccountId, Validator> =
+            UnorderedMap::try_from_slice(&validating_v1.validator_registry.try_to_vec().unwrap()).unwrap();
+        assert_eq!(validator_registry.try_to_vec().unwrap(), Fixture::new().unordered_map(VALIDATOR_STORAGE_KEY, 2).data);
+    }
+}